@@ -0,0 +1,42 @@
+//! Times evaluations of a population of Lobes on one input.
+//!
+//! Run it with and without the `parallel` feature to compare serial and
+//! parallel evaluation:
+//!
+//! ```text
+//! cargo bench --bench population
+//! cargo bench --bench population --features parallel
+//! ```
+
+use spiker::neural::lobe::{evaluate_population, Lobe};
+use spiker::rng::SplitMix64;
+use spiker::types::Amount;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+/// How long to keep evaluating each population for.
+const BUDGET: Duration = Duration::from_millis(500);
+
+fn main() {
+    for count in [4, 16, 64] {
+        let mut rng = SplitMix64::seed_from_u64(count as u64);
+        let mut lobes: Vec<Lobe> = (0..count)
+            .map(|_| Lobe::randomized(32, 32, Amount::from_num(0.5), &mut rng))
+            .collect();
+        let input = vec![Amount::ONE; 32];
+
+        let start = Instant::now();
+        let mut runs = 0;
+
+        while start.elapsed() < BUDGET {
+            black_box(evaluate_population(&mut lobes, &input, 8, 0.01));
+            runs += 1;
+        }
+
+        println!(
+            "{} Lobes: {:?} per evaluation",
+            count,
+            start.elapsed() / runs
+        );
+    }
+}
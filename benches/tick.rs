@@ -0,0 +1,39 @@
+//! Times ticks of square Lobes of several sizes.
+//!
+//! Run it with and without the `parallel` feature to compare serial and
+//! parallel ticks:
+//!
+//! ```text
+//! cargo bench --bench tick
+//! cargo bench --bench tick --features parallel
+//! ```
+
+use spiker::neural::base::NeuralObject;
+use spiker::neural::lobe::Lobe;
+use spiker::rng::SplitMix64;
+use spiker::types::Amount;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+/// How long to keep ticking each Lobe for.
+const BUDGET: Duration = Duration::from_millis(500);
+
+fn main() {
+    for size in [8, 64, 256, 512] {
+        let mut rng = SplitMix64::seed_from_u64(size as u64);
+        let mut lobe = Lobe::<Amount>::randomized(size, size, Amount::from_num(0.5), &mut rng);
+        let input = vec![Amount::ONE; size];
+
+        let start = Instant::now();
+        let mut ticks = 0;
+
+        while start.elapsed() < BUDGET {
+            lobe.apply_input(&input);
+            lobe.tick(0.01);
+            black_box(lobe.get_output());
+            ticks += 1;
+        }
+
+        println!("{0}x{0}: {1:?} per tick", size, start.elapsed() / ticks);
+    }
+}
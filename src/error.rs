@@ -0,0 +1,232 @@
+//! An error type covering the common failures of the crate's fallible
+//! operations, for callers that cannot afford a panic and would rather handle
+//! a single type.
+
+use crate::neural::base::SizeError;
+use crate::neural::lobe::{
+    BuildError, DecodeError, GeneticError, MergeError, OverflowError, ParamError, ValidationError,
+};
+use crate::neural::network::NetworkError;
+use core::fmt;
+
+/// A failure of one of the crate's fallible operations.
+///
+/// Methods that would otherwise panic have `try_` counterparts returning
+/// it, and the crate's more specific errors convert into it, so `?` works
+/// across them. Errors that fit none of the general variants are kept whole.
+#[derive(Clone, Debug, PartialEq)]
+pub enum SpikerError {
+    /// Two objects that must fit together have different dimensions.
+    DimensionMismatch {
+        expected: (usize, usize),
+        got: (usize, usize),
+    },
+
+    /// A list of inputs or parameters does not have the length called for.
+    ParameterLength { expected: usize, got: usize },
+
+    /// An index, such as a row or column, is not below the length it must
+    /// fit in.
+    IndexOutOfRange { index: usize, len: usize },
+
+    /// A tick duration is negative or NaN.
+    InvalidDuration(f64),
+
+    /// Two Lobes could not be merged.
+    Merge(MergeError),
+
+    /// Two Lobes could not be crossed over or swap parameters.
+    Genetic(GeneticError),
+
+    /// A network's layers do not fit together.
+    Network(NetworkError),
+
+    /// A binary Lobe could not be decoded.
+    Decode(DecodeError),
+
+    /// A Lobe failed validation.
+    Validation(ValidationError),
+
+    /// A checked tick would overflow.
+    Overflow(OverflowError),
+}
+
+impl fmt::Display for SpikerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SpikerError::DimensionMismatch { expected, got } => {
+                write!(f, "expected dimensions {:?}, but got {:?}", expected, got)
+            }
+            SpikerError::ParameterLength { expected, got } => {
+                write!(f, "expected {} entries, but got {}", expected, got)
+            }
+            SpikerError::IndexOutOfRange { index, len } => {
+                write!(f, "index {} is out of range for a length of {}", index, len)
+            }
+            SpikerError::InvalidDuration(duration_secs) => write!(
+                f,
+                "tick duration must be a non-negative number, got {}",
+                duration_secs
+            ),
+            SpikerError::Merge(error) => write!(f, "{}", error),
+            SpikerError::Genetic(error) => write!(f, "{}", error),
+            SpikerError::Network(error) => write!(f, "{}", error),
+            SpikerError::Decode(error) => write!(f, "{}", error),
+            SpikerError::Validation(error) => write!(f, "{}", error),
+            SpikerError::Overflow(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl core::error::Error for SpikerError {}
+
+impl From<SizeError> for SpikerError {
+    fn from(error: SizeError) -> Self {
+        SpikerError::ParameterLength {
+            expected: error.expected,
+            got: error.got,
+        }
+    }
+}
+
+impl From<ParamError> for SpikerError {
+    fn from(error: ParamError) -> Self {
+        match error {
+            ParamError::LengthMismatch { expected, got } => {
+                SpikerError::ParameterLength { expected, got }
+            }
+        }
+    }
+}
+
+impl From<BuildError> for SpikerError {
+    fn from(error: BuildError) -> Self {
+        match error {
+            BuildError::LengthMismatch { expected, got, .. } => {
+                SpikerError::ParameterLength { expected, got }
+            }
+        }
+    }
+}
+
+/// Implements `From` for errors kept whole in a variant of [SpikerError].
+macro_rules! wrap_error {
+    ($($error:ty => $variant:ident),*) => {$(
+        impl From<$error> for SpikerError {
+            fn from(error: $error) -> Self {
+                SpikerError::$variant(error)
+            }
+        }
+    )*};
+}
+
+wrap_error!(
+    MergeError => Merge,
+    GeneticError => Genetic,
+    NetworkError => Network,
+    DecodeError => Decode,
+    ValidationError => Validation,
+    OverflowError => Overflow
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::neural::base::NeuralObject;
+    use crate::neural::lobe::Lobe;
+    use crate::neural::network::Network;
+    use crate::types::Amount;
+    use alloc::vec;
+
+    #[test]
+    fn each_general_variant_has_a_trigger() {
+        let mut lobe = Lobe::<Amount>::new(2, 1, Amount::ZERO);
+        let other = Lobe::<Amount>::new(3, 1, Amount::ZERO);
+
+        assert_eq!(
+            lobe.try_restore(&other.snapshot()),
+            Err(SpikerError::DimensionMismatch {
+                expected: (1, 2),
+                got: (1, 3),
+            })
+        );
+        assert_eq!(
+            Lobe::<Amount>::try_from_parameters((1, 2), &[]).map_err(SpikerError::from),
+            Err(SpikerError::ParameterLength {
+                expected: lobe.all_parameters_owned().len(),
+                got: 0,
+            })
+        );
+        assert_eq!(
+            lobe.try_apply_input_at(2, Amount::ONE),
+            Err(SpikerError::IndexOutOfRange { index: 2, len: 2 })
+        );
+        assert!(matches!(
+            lobe.try_tick(f64::NAN),
+            Err(SpikerError::InvalidDuration(duration)) if duration.is_nan()
+        ));
+    }
+
+    #[test]
+    fn specific_errors_convert_with_the_question_mark() {
+        fn decode(bytes: &[u8]) -> Result<Lobe<Amount>, SpikerError> {
+            Ok(Lobe::from_bytes(bytes)?)
+        }
+
+        fn tick(lobe: &mut Lobe<Amount>) -> Result<(), SpikerError> {
+            Ok(lobe.tick_checked(1.0)?)
+        }
+
+        let mut lobe = Lobe::<Amount>::new(1, 1, Amount::ZERO);
+
+        *lobe.weight_between_mut(0, 0, 0).unwrap() = Amount::from_num(20);
+        lobe.strength_column_mut(0)[0] = Amount::ONE;
+        lobe.apply_input(&[Amount::from_num(20)]);
+
+        assert_eq!(
+            decode(b"nope"),
+            Err(SpikerError::Decode(DecodeError::BadMagic))
+        );
+        assert_eq!(
+            tick(&mut lobe),
+            Err(SpikerError::Overflow(OverflowError { neuron: 1 }))
+        );
+    }
+
+    #[test]
+    fn wraps_errors_without_a_general_variant() {
+        let lobe = Lobe::<Amount>::new(1, 1, Amount::ZERO);
+        let mut invalid = lobe.clone();
+
+        invalid.threshold_column_mut(0)[0] = -Amount::ONE;
+
+        assert_eq!(
+            lobe.concat_breadth(&Lobe::new(1, 2, Amount::ZERO))
+                .map_err(SpikerError::from)
+                .err(),
+            Some(SpikerError::Merge(MergeError::WidthMismatch {
+                left: 1,
+                right: 2
+            }))
+        );
+        assert_eq!(
+            lobe.crossover(&lobe, &[]).map_err(SpikerError::from).err(),
+            Some(SpikerError::Genetic(GeneticError::MaskLength {
+                expected: lobe.all_parameters_owned().len(),
+                got: 0,
+            }))
+        );
+        assert_eq!(
+            Network::<Amount>::new(vec![])
+                .map_err(SpikerError::from)
+                .err(),
+            Some(SpikerError::Network(NetworkError::Empty))
+        );
+        assert_eq!(
+            invalid.validate().map_err(SpikerError::from),
+            Err(SpikerError::Validation(
+                ValidationError::NegativeThreshold { index: 0 }
+            ))
+        );
+    }
+}
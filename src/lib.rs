@@ -1,2 +1,11 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+pub mod error;
 pub mod neural;
+pub mod prelude;
+pub mod rng;
+#[cfg(feature = "simd")]
+mod simd;
 pub mod types;
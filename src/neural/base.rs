@@ -1,9 +1,254 @@
-use crate::types::Amount;
+use crate::error::SpikerError;
+use crate::types::{Amount, Scalar};
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::fmt;
 
-pub trait NeuralObject {
+/// An input slice whose length does not match a neural object's input size.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SizeError {
+    pub expected: usize,
+    pub got: usize,
+}
+
+impl fmt::Display for SizeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "expected {} inputs, got {}", self.expected, self.got)
+    }
+}
+
+impl core::error::Error for SizeError {}
+
+/// Something that takes inputs, advances through time and produces outputs,
+/// such as a [Lobe](super::lobe::Lobe).
+///
+/// Implementors don't have to be `Send` or `Sync`, so boxed objects, and the
+/// Networks built from them, can't be assumed to cross threads. The
+/// built-in Lobe and [DenseLayer](super::dense::DenseLayer) are both `Send`
+/// and `Sync`.
+pub trait NeuralObject<S: Scalar = Amount> {
     fn input_size(&self) -> usize;
-    fn apply_input(&mut self, inputs: &[Amount]);
+
+    /// Adds the inputs to the object's input neurons.
+    ///
+    /// Inputs are matched up with the input neurons in order; if the lengths
+    /// differ, the excess is ignored. Use [NeuralObject::try_apply_input] to
+    /// reject mismatched inputs instead.
+    fn apply_input(&mut self, inputs: &[S]);
+
+    /// Like [NeuralObject::apply_input], but fails without applying anything
+    /// if `inputs` is not exactly [NeuralObject::input_size] long.
+    fn try_apply_input(&mut self, inputs: &[S]) -> Result<(), SizeError> {
+        let expected = self.input_size();
+
+        if inputs.len() != expected {
+            return Err(SizeError {
+                expected,
+                got: inputs.len(),
+            });
+        }
+
+        self.apply_input(inputs);
+
+        Ok(())
+    }
+
     fn tick(&mut self, duration_secs: f64);
-    fn get_output(&self) -> &[Amount];
-    fn reward(&mut self, reward: Amount);
+
+    /// Like [NeuralObject::tick], but fails without ticking if the duration
+    /// is negative or NaN, instead of leaving it to the object, which may
+    /// panic.
+    fn try_tick(&mut self, duration_secs: f64) -> Result<(), SpikerError> {
+        if duration_secs < 0.0 || duration_secs.is_nan() {
+            return Err(SpikerError::InvalidDuration(duration_secs));
+        }
+
+        self.tick(duration_secs);
+
+        Ok(())
+    }
+
+    /// Advances several timesteps of the same duration.
+    fn tick_n(&mut self, steps: usize, duration_secs: f64) {
+        for _ in 0..steps {
+            self.tick(duration_secs);
+        }
+    }
+
+    /// Advances through `total_secs`, split into as few equal timesteps as
+    /// possible while keeping each no longer than `max_dt`.
+    ///
+    /// Decay is integrated explicitly, one step at a time: every step removes
+    /// `value * falloff * dt` from each value. Once `falloff * dt` exceeds
+    /// one, a single step removes more than the whole value, so it overshoots
+    /// past zero and flips sign instead of settling, and beyond two it grows
+    /// every step. Spikes also arrive in bursts scaled by `dt`. Keeping steps
+    /// short avoids both problems.
+    ///
+    /// Panics if `total_secs` is not finite or `max_dt` is not positive.
+    fn tick_adaptive(&mut self, total_secs: f64, max_dt: f64) {
+        assert!(
+            total_secs.is_finite(),
+            "total_secs must be finite, got {}",
+            total_secs
+        );
+        assert!(max_dt > 0.0, "max_dt must be positive, got {}", max_dt);
+
+        if total_secs <= 0.0 {
+            return;
+        }
+
+        // Rounds up by hand, since `f64::ceil` needs the standard library.
+        let exact = total_secs / max_dt;
+        let steps = exact as usize + usize::from(exact > exact as usize as f64);
+        self.tick_n(steps, total_secs / steps as f64);
+    }
+
+    fn get_output(&self) -> &[S];
+
+    /// Returns the width of this object's output, without having to read it.
+    fn output_size(&self) -> usize {
+        self.get_output().len()
+    }
+
+    fn reward(&mut self, reward: S);
+
+    /// Clears transient state, such as accumulated values, while keeping
+    /// learned parameters intact.
+    fn reset(&mut self) {}
+
+    /// Returns how many seconds of simulated time have passed, as a clock
+    /// for time-dependent learning rules. Objects that don't keep track of
+    /// time return zero.
+    fn elapsed_secs(&self) -> f64 {
+        0.0
+    }
+
+    /// Clones this object into a new box, so that boxed objects, and the
+    /// Networks made of them, can be cloned.
+    fn clone_box(&self) -> Box<dyn NeuralObject<S>>;
+
+    /// Evaluates several independent samples, returning one output per
+    /// sample.
+    ///
+    /// For each sample, transient state is reset, the input is applied, and
+    /// `steps_per_sample` ticks of `duration_secs` are run before the output
+    /// is read. Nothing is rewarded, so learned parameters stay fixed.
+    fn run_batch(
+        &mut self,
+        inputs: &[Vec<S>],
+        steps_per_sample: usize,
+        duration_secs: f64,
+    ) -> Vec<Vec<S>> {
+        inputs
+            .iter()
+            .map(|input| {
+                self.reset();
+                self.apply_input(input);
+                self.tick_n(steps_per_sample, duration_secs);
+                self.get_output().to_vec()
+            })
+            .collect()
+    }
+}
+
+impl<S: Scalar> Clone for Box<dyn NeuralObject<S>> {
+    fn clone(&self) -> Self {
+        self.clone_box()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::neural::lobe::Lobe;
+    use alloc::vec;
+
+    #[test]
+    fn mismatched_inputs_are_rejected_untouched() {
+        let mut lobe = Lobe::<Amount>::new(2, 1, Amount::ZERO);
+
+        assert_eq!(
+            lobe.try_apply_input(&[Amount::ONE; 3]),
+            Err(SizeError {
+                expected: 2,
+                got: 3
+            })
+        );
+        assert_eq!(lobe.value_column_ref(0), [Amount::ZERO; 2]);
+
+        lobe.try_apply_input(&[Amount::ONE; 2]).unwrap();
+
+        assert_eq!(lobe.value_column_ref(0), [Amount::ONE; 2]);
+    }
+
+    #[test]
+    fn adaptive_ticks_split_the_duration_evenly() {
+        let mut lobe = Lobe::<Amount>::new(1, 1, Amount::ZERO);
+
+        lobe.tick_adaptive(1.0, 0.3);
+
+        assert_eq!(lobe.counted_ticks(), 4);
+
+        lobe.tick_adaptive(0.0, 0.3);
+        lobe.tick_adaptive(0.6, 0.3);
+
+        assert_eq!(lobe.counted_ticks(), 6);
+    }
+
+    #[test]
+    fn adaptive_ticks_track_fine_ticks_where_one_tick_overshoots() {
+        let leaking = || {
+            let mut lobe = Lobe::<f64>::new(1, 1, 1.5);
+
+            *lobe.value_at_mut(1, 0).unwrap() = 1.0;
+            lobe
+        };
+
+        let (mut coarse, mut adaptive, mut fine) = (leaking(), leaking(), leaking());
+
+        coarse.tick(1.0);
+        adaptive.tick_adaptive(1.0, 0.01);
+        fine.tick_n(1000, 0.001);
+
+        let (coarse, adaptive, fine) = (
+            coarse.get_output()[0],
+            adaptive.get_output()[0],
+            fine.get_output()[0],
+        );
+
+        // The single tick removes one and a half times the value.
+        assert_eq!(coarse, -0.5);
+        assert!(adaptive > 0.0);
+        assert!((adaptive - fine).abs() < 0.01, "{} vs {}", adaptive, fine);
+    }
+
+    #[test]
+    #[should_panic(expected = "max_dt must be positive")]
+    fn adaptive_ticks_need_a_positive_step() {
+        Lobe::<Amount>::new(1, 1, Amount::ZERO).tick_adaptive(1.0, 0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "total_secs must be finite")]
+    fn adaptive_ticks_need_a_finite_duration() {
+        Lobe::<Amount>::new(1, 1, Amount::ZERO).tick_adaptive(f64::INFINITY, 0.1);
+    }
+
+    #[test]
+    fn batches_evaluate_each_sample_from_a_clean_state() {
+        let mut lobe = Lobe::<Amount>::new(1, 1, Amount::ZERO);
+
+        lobe.set_weights_from(&[Amount::ONE; 3]).unwrap();
+        lobe.set_strengths_from(&[Amount::ONE]).unwrap();
+        lobe.set_thresholds_from(&[Amount::from_num(0.5)]).unwrap();
+
+        let samples = [Amount::ONE, Amount::ZERO, Amount::from_num(0.75)].map(|x| vec![x]);
+        let outputs = lobe.run_batch(&samples, 2, 1.0);
+
+        assert_eq!(
+            outputs,
+            [[Amount::ONE], [Amount::ZERO], [Amount::from_num(0.75)]]
+        );
+    }
 }
@@ -0,0 +1,64 @@
+use crate::types::Scalar;
+use alloc::vec::Vec;
+
+/// Returns the index of the neuron that fired the most. Ties go to the first
+/// such neuron.
+///
+/// For a classification readout of a Lobe, pass the last `breadth` of its
+/// [spike counts](super::lobe::Lobe::spike_counts), which belong to the last
+/// column that fires.
+///
+/// Panics if `counts` is empty.
+pub fn argmax_spikes(counts: &[u64]) -> usize {
+    assert!(!counts.is_empty(), "cannot pick a winner among no neurons");
+
+    let mut best = 0;
+
+    for (index, count) in counts.iter().enumerate() {
+        if *count > counts[best] {
+            best = index;
+        }
+    }
+
+    best
+}
+
+/// Normalizes spike counts into firing rates, in spikes per tick, given how
+/// many ticks they were counted over. All rates are zero if no ticks were
+/// counted.
+pub fn rate_vector<S: Scalar>(counts: &[u64], ticks: u64) -> Vec<S> {
+    counts
+        .iter()
+        .map(|count| match ticks {
+            0 => S::ZERO,
+            ticks => S::from_f64(*count as f64 / ticks as f64),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Amount;
+
+    #[test]
+    fn argmax_picks_the_first_busiest_neuron() {
+        assert_eq!(argmax_spikes(&[2, 7, 3, 7]), 1);
+        assert_eq!(argmax_spikes(&[0, 0]), 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn argmax_of_nothing_panics() {
+        argmax_spikes(&[]);
+    }
+
+    #[test]
+    fn rates_are_spikes_per_tick() {
+        assert_eq!(
+            rate_vector::<Amount>(&[0, 2, 4], 4),
+            [0.0, 0.5, 1.0].map(Amount::from_num)
+        );
+        assert_eq!(rate_vector::<f32>(&[3, 5], 0), [0.0, 0.0]);
+    }
+}
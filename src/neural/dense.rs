@@ -0,0 +1,263 @@
+use super::base::NeuralObject;
+use super::lobe::ParamError;
+use crate::types::{Amount, Scalar};
+use alloc::boxed::Box;
+use alloc::vec;
+use alloc::vec::Vec;
+use itertools::izip;
+
+/// The learning rate new DenseLayers start out with.
+const DEFAULT_LEARNING_RATE: f64 = 0.0625;
+
+/// A function applied to every output of a [DenseLayer].
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+pub enum Activation {
+    /// Passes values through unchanged.
+    #[default]
+    Identity,
+
+    /// Clamps negative values to zero.
+    Relu,
+
+    /// Squashes values into the range from zero to one. Needs the `std`
+    /// feature.
+    #[cfg(feature = "std")]
+    Sigmoid,
+}
+
+impl Activation {
+    /// Applies the activation function to a single value.
+    pub fn apply<S: Scalar>(self, value: S) -> S {
+        match self {
+            Activation::Identity => value,
+            Activation::Relu if value < S::ZERO => S::ZERO,
+            Activation::Relu => value,
+            #[cfg(feature = "std")]
+            Activation::Sigmoid => S::from_f64(1.0 / (1.0 + (-value.to_f64()).exp())),
+        }
+    }
+}
+
+/// A conventional, non-spiking fully connected layer, as a baseline to
+/// compare Lobes against.
+///
+/// Inputs accumulate until the next tick, which computes every output as the
+/// activation of a weighted sum of the inputs plus a bias, then clears the
+/// inputs. Rewards reinforce the connections between the inputs and outputs
+/// of the most recent tick.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DenseLayer<S: Scalar = Amount> {
+    dims: (usize, usize),
+    weights: Vec<S>,
+    biases: Vec<S>,
+    activation: Activation,
+    inputs: Vec<S>,
+    last_inputs: Vec<S>,
+    outputs: Vec<S>,
+    learning_rate: S,
+}
+
+// DenseLayers are compared against Lobes, so they should be just as
+// thread-safe.
+const _: fn() = || {
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    assert_send_sync::<DenseLayer<Amount>>();
+    assert_send_sync::<DenseLayer<f64>>();
+};
+
+impl<S: Scalar> DenseLayer<S> {
+    /// Creates a new DenseLayer with all weights and biases at zero.
+    pub fn new(input_size: usize, output_size: usize, activation: Activation) -> Self {
+        DenseLayer {
+            dims: (input_size, output_size),
+            weights: vec![S::ZERO; input_size * output_size],
+            biases: vec![S::ZERO; output_size],
+            activation,
+            inputs: vec![S::ZERO; input_size],
+            last_inputs: vec![S::ZERO; input_size],
+            outputs: vec![S::ZERO; output_size],
+            learning_rate: S::from_f64(DEFAULT_LEARNING_RATE),
+        }
+    }
+
+    /// Creates a new DenseLayer with the given weights and biases.
+    ///
+    /// The weights are laid out output by output, with one weight per input
+    /// for each output, and there is one bias per output.
+    pub fn with_weights(
+        input_size: usize,
+        output_size: usize,
+        activation: Activation,
+        weights: Vec<S>,
+        biases: Vec<S>,
+    ) -> Result<Self, ParamError> {
+        for (expected, got) in [
+            (input_size * output_size, weights.len()),
+            (output_size, biases.len()),
+        ] {
+            if expected != got {
+                return Err(ParamError::LengthMismatch { expected, got });
+            }
+        }
+
+        let mut layer = Self::new(input_size, output_size, activation);
+
+        layer.weights = weights;
+        layer.biases = biases;
+
+        Ok(layer)
+    }
+
+    /// References the weights, laid out output by output.
+    pub fn weights(&self) -> &[S] {
+        &self.weights
+    }
+
+    /// Mutably references the weights, laid out output by output.
+    pub fn weights_mut(&mut self) -> &mut [S] {
+        &mut self.weights
+    }
+
+    /// References the biases, one per output.
+    pub fn biases(&self) -> &[S] {
+        &self.biases
+    }
+
+    /// Mutably references the biases, one per output.
+    pub fn biases_mut(&mut self) -> &mut [S] {
+        &mut self.biases
+    }
+
+    /// Returns the activation function applied to every output.
+    pub fn activation(&self) -> Activation {
+        self.activation
+    }
+
+    /// Returns the rate at which rewards change the weights and biases.
+    pub fn learning_rate(&self) -> S {
+        self.learning_rate
+    }
+
+    /// Sets the rate at which rewards change the weights and biases.
+    pub fn set_learning_rate(&mut self, value: S) {
+        self.learning_rate = value;
+    }
+}
+
+impl<S: Scalar> NeuralObject<S> for DenseLayer<S> {
+    fn input_size(&self) -> usize {
+        self.dims.0
+    }
+
+    fn apply_input(&mut self, inputs: &[S]) {
+        for (into, from) in izip!(&mut self.inputs, inputs) {
+            *into += *from;
+        }
+    }
+
+    fn tick(&mut self, _duration_secs: f64) {
+        let input_size = self.dims.0;
+
+        for (index, (output, bias)) in izip!(&mut self.outputs, &self.biases).enumerate() {
+            let weights = &self.weights[index * input_size..(index + 1) * input_size];
+            let mut sum = *bias;
+
+            for (weight, input) in izip!(weights, &self.inputs) {
+                sum += *weight * *input;
+            }
+
+            *output = self.activation.apply(sum);
+        }
+
+        self.last_inputs.copy_from_slice(&self.inputs);
+        self.inputs.fill(S::ZERO);
+    }
+
+    fn get_output(&self) -> &[S] {
+        &self.outputs
+    }
+
+    fn output_size(&self) -> usize {
+        self.dims.1
+    }
+
+    /// Applies a reward-modulated Hebbian update: every weight moves by
+    /// `reward * learning_rate` times the product of its input and output on
+    /// the most recent tick, and every bias by that times its output.
+    fn reward(&mut self, reward: S) {
+        let delta = reward * self.learning_rate;
+        let input_size = self.dims.0;
+
+        for (index, (bias, output)) in izip!(&mut self.biases, &self.outputs).enumerate() {
+            let weights = &mut self.weights[index * input_size..(index + 1) * input_size];
+            let delta = delta * *output;
+
+            for (weight, input) in izip!(weights, &self.last_inputs) {
+                *weight += delta * *input;
+            }
+
+            *bias += delta;
+        }
+    }
+
+    fn reset(&mut self) {
+        self.inputs.fill(S::ZERO);
+        self.last_inputs.fill(S::ZERO);
+        self.outputs.fill(S::ZERO);
+    }
+
+    fn clone_box(&self) -> Box<dyn NeuralObject<S>> {
+        Box::new(self.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn layer() -> DenseLayer {
+        let weights = [1.0, -1.0, 0.5, 0.5].map(Amount::from_num).to_vec();
+        let biases = [0.0, -2.0].map(Amount::from_num).to_vec();
+
+        DenseLayer::with_weights(2, 2, Activation::Relu, weights, biases).unwrap()
+    }
+
+    #[test]
+    fn outputs_activate_weighted_sums_of_the_inputs() {
+        let mut layer = layer();
+
+        layer.apply_input(&[Amount::ONE, Amount::from_num(0.5)]);
+        layer.tick(1.0);
+
+        assert_eq!(layer.get_output(), [Amount::from_num(0.5), Amount::ZERO]);
+
+        layer.tick(1.0);
+
+        assert_eq!(layer.get_output(), [Amount::ZERO; 2]);
+    }
+
+    #[test]
+    fn rewards_reinforce_the_last_ticks_connections() {
+        let mut layer = layer();
+
+        layer.set_learning_rate(Amount::ONE);
+        layer.apply_input(&[Amount::ONE, Amount::ZERO]);
+        layer.tick(1.0);
+        layer.reward(Amount::ONE);
+
+        assert_eq!(layer.weights(), [2.0, -1.0, 0.5, 0.5].map(Amount::from_num));
+        assert_eq!(layer.biases(), [1.0, -2.0].map(Amount::from_num));
+    }
+
+    #[test]
+    fn rejects_parameters_of_the_wrong_length() {
+        assert_eq!(
+            DenseLayer::<Amount>::with_weights(2, 2, Activation::Identity, vec![], vec![]),
+            Err(ParamError::LengthMismatch {
+                expected: 4,
+                got: 0
+            })
+        );
+    }
+}
@@ -0,0 +1,160 @@
+use super::base::NeuralObject;
+use crate::types::{Amount, Scalar};
+use alloc::boxed::Box;
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Wraps a neural object, feeding it a predefined input on every tick.
+///
+/// Each tick first applies the next scheduled input, then ticks the wrapped
+/// object. Once the schedule runs out, zeros are applied instead.
+#[derive(Clone)]
+pub struct ScheduledDriver<N: NeuralObject<S>, S: Scalar = Amount> {
+    inner: N,
+    schedule: Vec<Vec<S>>,
+    cursor: usize,
+}
+
+impl<N: NeuralObject<S>, S: Scalar> ScheduledDriver<N, S> {
+    /// Creates a new ScheduledDriver around a neural object, starting at the
+    /// first step of the schedule.
+    pub fn new(inner: N, schedule: Vec<Vec<S>>) -> Self {
+        Self {
+            inner,
+            schedule,
+            cursor: 0,
+        }
+    }
+
+    /// References the per-tick inputs, in order.
+    pub fn schedule(&self) -> &[Vec<S>] {
+        &self.schedule
+    }
+
+    /// Returns the index of the schedule step the next tick will apply.
+    pub fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    /// Whether every step of the schedule has been applied.
+    pub fn is_exhausted(&self) -> bool {
+        self.cursor >= self.schedule.len()
+    }
+
+    /// Goes back to the first step of the schedule.
+    pub fn rewind(&mut self) {
+        self.cursor = 0;
+    }
+
+    /// References the wrapped neural object.
+    pub fn inner(&self) -> &N {
+        &self.inner
+    }
+
+    /// Mutably references the wrapped neural object.
+    pub fn inner_mut(&mut self) -> &mut N {
+        &mut self.inner
+    }
+
+    /// Unwraps the neural object, discarding the schedule.
+    pub fn into_inner(self) -> N {
+        self.inner
+    }
+}
+
+impl<N: NeuralObject<S> + Clone + 'static, S: Scalar> NeuralObject<S> for ScheduledDriver<N, S> {
+    fn input_size(&self) -> usize {
+        self.inner.input_size()
+    }
+
+    fn apply_input(&mut self, inputs: &[S]) {
+        self.inner.apply_input(inputs);
+    }
+
+    fn tick(&mut self, duration_secs: f64) {
+        match self.schedule.get(self.cursor) {
+            Some(inputs) => {
+                self.inner.apply_input(inputs);
+                self.cursor += 1;
+            }
+            None => self
+                .inner
+                .apply_input(&vec![S::ZERO; self.inner.input_size()]),
+        }
+
+        self.inner.tick(duration_secs);
+    }
+
+    fn get_output(&self) -> &[S] {
+        self.inner.get_output()
+    }
+
+    fn output_size(&self) -> usize {
+        self.inner.output_size()
+    }
+
+    fn reward(&mut self, reward: S) {
+        self.inner.reward(reward);
+    }
+
+    fn elapsed_secs(&self) -> f64 {
+        self.inner.elapsed_secs()
+    }
+
+    /// Resets the wrapped object and rewinds the schedule.
+    fn reset(&mut self) {
+        self.inner.reset();
+        self.rewind();
+    }
+
+    fn clone_box(&self) -> Box<dyn NeuralObject<S>> {
+        Box::new(self.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::neural::lobe::Lobe;
+
+    /// A Lobe that never fires, so its input column sums what it is given.
+    fn summing() -> Lobe {
+        let mut lobe = Lobe::new(1, 1, Amount::ZERO);
+
+        lobe.set_thresholds_from(&[Amount::from_num(8)]).unwrap();
+        lobe
+    }
+
+    #[test]
+    fn applies_one_step_per_tick_then_zeros() {
+        let schedule = vec![vec![Amount::ONE], vec![Amount::from_num(0.5)]];
+        let mut driver = ScheduledDriver::new(summing(), schedule);
+
+        driver.tick(1.0);
+
+        assert_eq!(driver.inner().value_at(0, 0), Some(Amount::ONE));
+        assert!(!driver.is_exhausted());
+
+        driver.tick(1.0);
+        driver.tick(1.0);
+
+        assert_eq!(driver.inner().value_at(0, 0), Some(Amount::from_num(1.5)));
+        assert_eq!(driver.cursor(), 2);
+        assert!(driver.is_exhausted());
+    }
+
+    #[test]
+    fn reset_rewinds_the_schedule() {
+        let mut driver = ScheduledDriver::new(summing(), vec![vec![Amount::ONE]]);
+
+        driver.tick(1.0);
+        driver.reset();
+
+        assert_eq!(driver.cursor(), 0);
+        assert_eq!(driver.inner().value_at(0, 0), Some(Amount::ZERO));
+
+        driver.tick(1.0);
+
+        assert_eq!(driver.inner().value_at(0, 0), Some(Amount::ONE));
+    }
+}
@@ -0,0 +1,146 @@
+use crate::rng::Rng;
+use crate::types::{Amount, Scalar};
+use alloc::vec;
+use alloc::vec::Vec;
+use itertools::izip;
+
+/// Converts continuous signals into spikes, using rate coding.
+///
+/// Each channel takes a value from zero to one, and emits spikes at a rate
+/// proportional to it, up to `max_rate` spikes per second at one. Every spike
+/// is an input of `amount`, ready to pass to
+/// [NeuralObject::apply_input](super::base::NeuralObject::apply_input).
+#[derive(Clone, Debug, PartialEq)]
+pub struct RateEncoder<S: Scalar = Amount> {
+    max_rate: f64,
+    amount: S,
+    phases: Vec<f64>,
+}
+
+impl<S: Scalar> RateEncoder<S> {
+    /// Creates a new RateEncoder for the given number of channels.
+    pub fn new(channels: usize, max_rate: f64, amount: S) -> Self {
+        Self {
+            max_rate,
+            amount,
+            phases: vec![0.0; channels],
+        }
+    }
+
+    /// Returns the number of channels.
+    pub fn channels(&self) -> usize {
+        self.phases.len()
+    }
+
+    /// Returns the rate, in spikes per second, of a channel at one.
+    pub fn max_rate(&self) -> f64 {
+        self.max_rate
+    }
+
+    /// Returns the input amount of each spike.
+    pub fn amount(&self) -> S {
+        self.amount
+    }
+
+    /// Encodes one timestep of `dt` seconds deterministically, spacing each
+    /// channel's spikes out evenly.
+    ///
+    /// Every channel accumulates the spikes it should emit on average, which
+    /// is its value clamped to the range from zero to one, times `max_rate`,
+    /// times `dt`. It emits one spike for every whole spike accumulated so
+    /// far; several spikes in the same timestep add up.
+    pub fn encode(&mut self, values: &[f64], dt: f64) -> Vec<S> {
+        let mut inputs = vec![S::ZERO; self.phases.len()];
+        let rate = self.max_rate * dt;
+
+        for (input, phase, value) in izip!(&mut inputs, &mut self.phases, values) {
+            *phase += value.clamp(0.0, 1.0) * rate;
+
+            let spikes = *phase as u64;
+
+            *phase -= spikes as f64;
+
+            for _ in 0..spikes {
+                *input += self.amount;
+            }
+        }
+
+        inputs
+    }
+
+    /// Encodes one timestep of `dt` seconds randomly, as a Poisson process.
+    ///
+    /// Every channel emits a spike with probability equal to the spikes it
+    /// should emit on average in the timestep, so `dt` should be small enough
+    /// for that to stay below one.
+    pub fn encode_poisson(&self, values: &[f64], dt: f64, rng: &mut impl Rng) -> Vec<S> {
+        let mut inputs = vec![S::ZERO; self.phases.len()];
+        let rate = self.max_rate * dt;
+
+        for (input, value) in izip!(&mut inputs, values) {
+            if rng.gen_f64() < value.clamp(0.0, 1.0) * rate {
+                *input = self.amount;
+            }
+        }
+
+        inputs
+    }
+
+    /// Clears the spikes accumulated by [RateEncoder::encode].
+    pub fn reset(&mut self) {
+        self.phases.fill(0.0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::SplitMix64;
+
+    #[test]
+    fn deterministic_rates_follow_the_values() {
+        let mut encoder = RateEncoder::new(3, 10.0, Amount::ONE);
+        let mut totals = [Amount::ZERO; 3];
+
+        for _ in 0..10 {
+            let inputs = encoder.encode(&[0.0, 0.5, 2.0], 0.1);
+
+            for (total, input) in totals.iter_mut().zip(inputs) {
+                *total += input;
+            }
+        }
+
+        assert_eq!(totals, [0, 5, 10].map(Amount::from_num));
+    }
+
+    #[test]
+    fn several_spikes_in_one_step_add_up() {
+        let mut encoder = RateEncoder::new(1, 30.0, Amount::from_num(0.5));
+
+        assert_eq!(encoder.encode(&[1.0], 0.1), [Amount::from_num(1.5)]);
+
+        encoder.reset();
+
+        assert_eq!(encoder.encode(&[0.05], 0.1), [Amount::ZERO]);
+    }
+
+    #[test]
+    fn poisson_rates_follow_the_values_on_average() {
+        let encoder = RateEncoder::new(2, 100.0, Amount::ONE);
+        let mut rng = SplitMix64::seed_from_u64(12);
+        let mut spikes = [0; 2];
+
+        for _ in 0..2000 {
+            for (count, input) in
+                spikes
+                    .iter_mut()
+                    .zip(encoder.encode_poisson(&[0.2, 0.6], 0.01, &mut rng))
+            {
+                *count += usize::from(input == Amount::ONE);
+            }
+        }
+
+        assert!((300..500).contains(&spikes[0]), "{:?}", spikes);
+        assert!((1100..1300).contains(&spikes[1]), "{:?}", spikes);
+    }
+}
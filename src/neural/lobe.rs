@@ -1,212 +1,4046 @@
-use super::base::NeuralObject;
-use crate::types::Amount;
+use super::base::{NeuralObject, SizeError};
+use crate::error::SpikerError;
+use crate::rng::{Distribution, Rng};
+use crate::types::{Amount, Scalar};
+use alloc::boxed::Box;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::{Index, IndexMut, Range};
+use core::slice::{self, Chunks, ChunksMut};
 use itertools::izip;
-use std::slice::{self, Chunks, ChunksMut};
+
+mod bytes;
+mod checked;
+mod connectivity;
+mod delay;
+mod evolve;
+#[cfg(feature = "std")]
+mod export;
+mod fingerprint;
+mod learning;
+mod merge;
+mod neuron;
+mod overflow;
+mod population;
+mod profile;
+mod propagation;
+mod resize;
+mod snapshot;
+mod stochastic;
+#[cfg(feature = "parallel")]
+mod threads;
+mod trainable;
+mod transfer;
+mod validate;
+
+pub use bytes::DecodeError;
+pub use checked::OverflowError;
+pub use connectivity::{Connectivity, NeighborOffset};
+pub use evolve::GeneticError;
+pub use learning::{HebbianRule, LearningRule, NullRule};
+pub use merge::MergeError;
+pub use neuron::{NeuronView, NeuronViewMut};
+pub use overflow::OverflowMode;
+pub use population::evaluate_population;
+#[cfg(feature = "profiling")]
+pub use profile::TickProfile;
+pub use propagation::PropagationMode;
+pub use snapshot::LobeSnapshot;
+pub use stochastic::StochasticFiring;
+pub use trainable::{ParamGroup, TrainableMask};
+pub use transfer::Transfer;
+pub use validate::ValidationError;
+
+use delay::DelayLine;
+use profile::{Phase, Stopwatch};
+
+/// The learning rate new Lobes start out with.
+const DEFAULT_LEARNING_RATE: f64 = 0.0625;
+
+/// How close to zero a weight must be for [Lobe::sparsity] to count it as
+/// zero.
+const SPARSITY_EPSILON: f64 = 1e-6;
 
 /// A rectangular cluster of spiking neurons.
+///
+/// Lobes are `Send` and `Sync`, so they can be moved to and shared between
+/// threads, as [evaluate_population] does. Everything they hold, including
+/// any [LearningRule], must keep it that way.
 #[derive(Clone, Eq, PartialEq)]
-pub struct Lobe {
+pub struct Lobe<S: Scalar = Amount> {
     dims: (usize, usize),
-    values: Vec<Amount>,
-    strengths: Vec<Amount>,
-    weights: Vec<Amount>,
-    thresholds: Vec<Amount>,
-    falloff: Amount,
+    values: Vec<S>,
+    strengths: Vec<S>,
+    weights: Vec<S>,
+    thresholds: Vec<S>,
+    falloff: S,
+    fired: Vec<bool>,
+    learning_rate: S,
+    eligibility: Vec<S>,
+    trace_decay: S,
+    refractory_ticks: u32,
+    refractory: Vec<u32>,
+    min_value: S,
+    reset_value: S,
+    denoise_epsilon: Option<S>,
+    spike_counts: Vec<u64>,
+    counted_ticks: u64,
+    rate_alpha: Option<S>,
+    firing_rates: Vec<S>,
+    elapsed: Clock,
+    falloff_per_neuron: Option<Vec<S>>,
+    bias: Option<Vec<S>>,
+    input_offset: Option<Vec<S>>,
+    input_scale: Option<Vec<S>>,
+    scratch: Scratch<S::Wide>,
+    back: Scratch<S>,
+    connectivity: Connectivity,
+    recurrent_gain: S,
+    global_gain: S,
+    lateral_inhibition: S,
+    propagation_mode: PropagationMode,
+    overflow_mode: OverflowMode,
+    stochastic: Option<StochasticFiring<S>>,
+    transfer: Transfer<S>,
+    tied: bool,
+    delay: DelayLine<S::Wide>,
+    learning_rule: learning::Rule<S>,
+    trainable: TrainableMask,
+    #[cfg(feature = "profiling")]
+    last_profile: profile::LastProfile,
+}
+
+// Lobes holding a learning rule must stay usable across `catch_unwind`.
+const _: fn() = || {
+    use core::panic::{RefUnwindSafe, UnwindSafe};
+
+    fn assert_unwind_safe<T: UnwindSafe + RefUnwindSafe>() {}
+
+    assert_unwind_safe::<Lobe<Amount>>();
+    assert_unwind_safe::<Lobe<f32>>();
+    assert_unwind_safe::<Lobe<f64>>();
+};
+
+// Parallel propagation and population evaluation rely on Lobes being
+// thread-safe, so losing it should fail to compile.
+const _: fn() = || {
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    assert_send_sync::<Lobe<Amount>>();
+    assert_send_sync::<Lobe<f32>>();
+    assert_send_sync::<Lobe<f64>>();
+};
+
+/// A reusable buffer that holds no meaningful state between ticks.
+///
+/// It always compares equal and is not copied when cloned, so it never makes
+/// two otherwise identical Lobes differ.
+struct Scratch<S>(Vec<S>);
+
+impl<S> Clone for Scratch<S> {
+    fn clone(&self) -> Self {
+        Self(vec![])
+    }
+}
+
+impl<S> PartialEq for Scratch<S> {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl<S> Eq for Scratch<S> {}
+
+/// Simulated time, in seconds.
+///
+/// It compares by its exact bits, so that Lobes can still be [Eq].
+#[derive(Clone, Copy, Default)]
+struct Clock(f64);
+
+impl PartialEq for Clock {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.to_bits() == other.0.to_bits()
+    }
+}
+
+impl Eq for Clock {}
+
+/// An error found while loading a Lobe's parameters.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParamError {
+    /// The parameter list does not have the length the dimensions call for.
+    LengthMismatch { expected: usize, got: usize },
+}
+
+impl fmt::Display for ParamError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParamError::LengthMismatch { expected, got } => {
+                write!(f, "expected {} parameters, but got {}", expected, got)
+            }
+        }
+    }
+}
+
+impl core::error::Error for ParamError {}
+
+/// Controls the distributions used by [Lobe::randomized_with].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RandomLobeConfig {
+    /// Distribution of the forward weights.
+    pub weights: Distribution,
+
+    /// Distribution of the firing strengths.
+    pub strengths: Distribution,
+
+    /// Distribution of the firing thresholds. Samples are made positive.
+    pub thresholds: Distribution,
+}
+
+impl Default for RandomLobeConfig {
+    fn default() -> Self {
+        Self {
+            weights: Distribution::Uniform {
+                low: 0.0,
+                high: 1.0,
+            },
+            strengths: Distribution::Uniform {
+                low: 0.0,
+                high: 1.0,
+            },
+            thresholds: Distribution::Uniform {
+                low: 0.25,
+                high: 1.0,
+            },
+        }
+    }
 }
 
-impl Lobe {
+impl<S: Scalar> Lobe<S> {
     /// Create a new Lobe from a pair of dimensions and a falloff value.
-    pub fn new(breadth: usize, width: usize, falloff: Amount) -> Self {
+    ///
+    /// Either dimension may be zero. A Lobe of width zero has its input
+    /// column double as its output column, and a Lobe of breadth zero has no
+    /// neurons at all: it takes and outputs empty slices, and ticking it does
+    /// nothing.
+    pub fn new(breadth: usize, width: usize, falloff: S) -> Self {
+        Self::with_connectivity(breadth, width, falloff, Connectivity::default())
+    }
+
+    /// Create a new Lobe from a pair of dimensions, a falloff value, and the
+    /// pattern its neurons are connected in.
+    pub fn with_connectivity(
+        breadth: usize,
+        width: usize,
+        falloff: S,
+        connectivity: Connectivity,
+    ) -> Self {
         Lobe {
             dims: (width, breadth),
-            values: vec![Amount::from_num(0); breadth * (width + 1)],
-            weights: vec![Amount::from_num(0); breadth * width * 3],
-            strengths: vec![Amount::from_num(0); breadth * width],
-            thresholds: vec![Amount::from_num(0); breadth * width],
+            values: vec![S::ZERO; breadth * (width + 1)],
+            weights: vec![S::ZERO; breadth * width * connectivity.neighbors()],
+            strengths: vec![S::ZERO; breadth * width],
+            thresholds: vec![S::ZERO; breadth * width],
             falloff,
+            fired: vec![false; breadth * width],
+            learning_rate: S::from_f64(DEFAULT_LEARNING_RATE),
+            eligibility: vec![S::ZERO; breadth * width * connectivity.neighbors()],
+            trace_decay: S::ONE,
+            refractory_ticks: 0,
+            refractory: vec![0; breadth * width],
+            min_value: S::ZERO,
+            reset_value: S::ZERO,
+            denoise_epsilon: None,
+            spike_counts: vec![0; breadth * width],
+            counted_ticks: 0,
+            rate_alpha: None,
+            firing_rates: vec![],
+            elapsed: Clock::default(),
+            falloff_per_neuron: None,
+            bias: None,
+            input_offset: None,
+            input_scale: None,
+            scratch: Scratch(vec![Scalar::ZERO; breadth * (width + 1)]),
+            back: Scratch(vec![S::ZERO; breadth * (width + 1)]),
+            connectivity,
+            recurrent_gain: S::ZERO,
+            global_gain: S::ONE,
+            lateral_inhibition: S::ZERO,
+            propagation_mode: PropagationMode::default(),
+            overflow_mode: OverflowMode::default(),
+            stochastic: None,
+            transfer: Transfer::Linear,
+            tied: false,
+            delay: DelayLine::new(0, 0),
+            learning_rule: learning::Rule(None),
+            trainable: TrainableMask::default(),
+            #[cfg(feature = "profiling")]
+            last_profile: Default::default(),
+        }
+    }
+
+    /// Starts building a new Lobe; see [LobeBuilder].
+    pub fn builder() -> LobeBuilder<S> {
+        LobeBuilder::new()
+    }
+
+    /// Create a new Lobe with parameters sampled from the default
+    /// [RandomLobeConfig].
+    pub fn randomized(breadth: usize, width: usize, falloff: S, rng: &mut impl Rng) -> Self {
+        Self::randomized_with(breadth, width, falloff, &RandomLobeConfig::default(), rng)
+    }
+
+    /// Create a new Lobe with parameters sampled from the given distributions.
+    pub fn randomized_with(
+        breadth: usize,
+        width: usize,
+        falloff: S,
+        config: &RandomLobeConfig,
+        rng: &mut impl Rng,
+    ) -> Self {
+        let mut lobe = Self::new(breadth, width, falloff);
+
+        for weight in &mut lobe.weights {
+            *weight = S::from_f64(config.weights.sample(rng));
+        }
+
+        for strength in &mut lobe.strengths {
+            *strength = S::from_f64(config.strengths.sample(rng));
+        }
+
+        for threshold in &mut lobe.thresholds {
+            *threshold = S::from_f64(config.thresholds.sample(rng).abs());
+        }
+
+        lobe
+    }
+
+    /// Redraws every forward weight from a zero-mean normal distribution
+    /// with Xavier (Glorot) scaling, a variance of `1 / fan_in`.
+    ///
+    /// Each neuron receives from [Connectivity::neighbors] others, which is
+    /// taken as its fan-in. Scaling by it keeps the total input a neuron
+    /// receives from its firing neighbors on the same order as a single
+    /// neuron's value, so activity neither dies out nor blows up as it moves
+    /// across columns. Needs the `std` feature, for its floating-point math.
+    #[cfg(feature = "std")]
+    pub fn init_weights_xavier(&mut self, rng: &mut impl Rng) {
+        let fan_in = self.connectivity.neighbors() as f64;
+        self.init_weights_normal((1.0 / fan_in).sqrt(), rng);
+    }
+
+    /// Redraws every forward weight from a zero-mean normal distribution
+    /// with He scaling, a variance of `2 / fan_in`.
+    ///
+    /// Like [Lobe::init_weights_xavier], but twice the variance, making up
+    /// for the neighbors that sit below their threshold and send nothing.
+    /// Needs the `std` feature, for its floating-point math.
+    #[cfg(feature = "std")]
+    pub fn init_weights_he(&mut self, rng: &mut impl Rng) {
+        let fan_in = self.connectivity.neighbors() as f64;
+        self.init_weights_normal((2.0 / fan_in).sqrt(), rng);
+    }
+
+    /// Redraws every forward weight from a zero-mean normal distribution.
+    #[cfg(feature = "std")]
+    fn init_weights_normal(&mut self, std_dev: f64, rng: &mut impl Rng) {
+        for weight in &mut self.weights {
+            *weight = S::from_f64(rng.gen_normal(0.0, std_dev));
         }
+
+        self.sync_tied();
     }
 
     /// References a column of the Lobe's values.
-    pub fn value_column_ref(&self, which: usize) -> &[Amount] {
+    pub fn value_column_ref(&self, which: usize) -> &[S] {
         &self.values[which * self.dims.1..(which + 1) * self.dims.1]
     }
 
     /// Mutably references a column of the Lobe's values.
-    pub fn value_column_mut(&mut self, which: usize) -> &mut [Amount] {
+    pub fn value_column_mut(&mut self, which: usize) -> &mut [S] {
         &mut self.values[which * self.dims.1..(which + 1) * self.dims.1]
     }
 
-    /// Iterates on the columns of the Lobe's values.
-    pub fn values_chunked(&self) -> Chunks<Amount> {
-        self.values.chunks(self.dims.1)
+    /// Reads any column's values as if it were the output, to tap activity
+    /// midway through the Lobe, or `None` if `col` is past the output column.
+    ///
+    /// Column zero holds the inputs, and column `dims.0` is the output
+    /// returned by [NeuralObject::get_output].
+    pub fn column_output(&self, col: usize) -> Option<&[S]> {
+        (col <= self.dims.0).then(|| self.value_column_ref(col))
+    }
+
+    /// Iterates on the columns of the Lobe's values. A Lobe of breadth zero
+    /// has no columns to iterate on.
+    pub fn values_chunked(&self) -> Chunks<'_, S> {
+        self.values.chunks(self.dims.1.max(1))
     }
 
     /// Mutably iterates on the columns of the Lobe's values.
-    pub fn values_chunked_mut(&mut self) -> ChunksMut<Amount> {
-        self.values.chunks_mut(self.dims.1)
+    pub fn values_chunked_mut(&mut self) -> ChunksMut<'_, S> {
+        self.values.chunks_mut(self.dims.1.max(1))
     }
 
-    /// References a column of the Lobe's firing strengths.
-    pub fn strength_column_ref(&self, which: usize) -> &[Amount] {
+    /// References a column of the Lobe's firing strengths. While tied, every
+    /// column references the template; see [Lobe::set_tied].
+    pub fn strength_column_ref(&self, which: usize) -> &[S] {
+        let which = self.param_column(which);
         &self.strengths[which * self.dims.1..(which + 1) * self.dims.1]
     }
 
     /// Mutably references a column of the Lobe's firing strengths.
-    pub fn strength_column_mut(&mut self, which: usize) -> &mut [Amount] {
+    pub fn strength_column_mut(&mut self, which: usize) -> &mut [S] {
+        let which = self.param_column(which);
         &mut self.strengths[which * self.dims.1..(which + 1) * self.dims.1]
     }
 
     /// Iterates on the columns of the Lobe's firing strengths.
-    pub fn strengths_chunked(&self) -> Chunks<Amount> {
-        self.strengths.chunks(self.dims.1)
+    pub fn strengths_chunked(&self) -> Chunks<'_, S> {
+        self.strengths.chunks(self.dims.1.max(1))
     }
 
-    /// References a column of the Lobe's firing thresholds.
-    pub fn threshold_column_ref(&self, which: usize) -> &[Amount] {
+    /// References a column of the Lobe's firing thresholds. While tied, every
+    /// column references the template; see [Lobe::set_tied].
+    pub fn threshold_column_ref(&self, which: usize) -> &[S] {
+        let which = self.param_column(which);
         &self.thresholds[which * self.dims.1..(which + 1) * self.dims.1]
     }
 
     /// Mutably references a column of the Lobe's firing thresholds.
-    pub fn threshold_column_mut(&mut self, which: usize) -> &mut [Amount] {
+    pub fn threshold_column_mut(&mut self, which: usize) -> &mut [S] {
+        let which = self.param_column(which);
         &mut self.thresholds[which * self.dims.1..(which + 1) * self.dims.1]
     }
 
     /// Iterates on the columns of the Lobe's firing thresholds.
-    pub fn thresholds_chunked(&self) -> Chunks<Amount> {
-        self.thresholds.chunks(self.dims.1)
+    pub fn thresholds_chunked(&self) -> Chunks<'_, S> {
+        self.thresholds.chunks(self.dims.1.max(1))
+    }
+
+    /// Strides through a column-major buffer with `columns` columns, picking
+    /// one row from each column. Yields nothing if the row is out of range.
+    fn row_iter<'a, T>(
+        &self,
+        buffer: &'a [T],
+        row: usize,
+        columns: usize,
+    ) -> impl Iterator<Item = &'a T> {
+        let breadth = self.dims.1;
+        let columns = if row < breadth { columns } else { 0 };
+
+        buffer
+            .iter()
+            .skip(row)
+            .step_by(breadth.max(1))
+            .take(columns)
+    }
+
+    /// Iterates on a row of the Lobe's values, across every column including
+    /// the output column.
+    pub fn value_row(&self, row: usize) -> impl Iterator<Item = &S> {
+        self.row_iter(&self.values, row, self.dims.0 + 1)
+    }
+
+    /// Iterates on a row of the Lobe's firing strengths, across every column.
+    pub fn strength_row(&self, row: usize) -> impl Iterator<Item = &S> {
+        self.row_iter(&self.strengths, row, self.dims.0)
+    }
+
+    /// Iterates on a row of the Lobe's firing thresholds, across every column.
+    pub fn threshold_row(&self, row: usize) -> impl Iterator<Item = &S> {
+        self.row_iter(&self.thresholds, row, self.dims.0)
+    }
+
+    /// Translates a `(col, row)` position into a flat index into a buffer
+    /// with `columns` columns, or `None` if it is out of range.
+    fn flat_index(&self, col: usize, row: usize, columns: usize) -> Option<usize> {
+        (col < columns && row < self.dims.1).then(|| col * self.dims.1 + row)
+    }
+
+    /// Translates a `(col, row)` position into a flat index into the values,
+    /// panicking if it is out of range.
+    fn value_index(&self, (col, row): (usize, usize)) -> usize {
+        match self.flat_index(col, row, self.dims.0 + 1) {
+            Some(index) => index,
+            None => panic!(
+                "neuron ({}, {}) is out of range for a Lobe of dims {:?}",
+                col, row, self.dims
+            ),
+        }
+    }
+
+    /// Reads the value of the neuron at a column and row.
+    ///
+    /// Like every other buffer in a Lobe, values are stored column by column,
+    /// so `(col, row)` lives at `col * breadth + row`. Values have one more
+    /// column than the other parameters: the output column, at `col == dims.0`.
+    pub fn value_at(&self, col: usize, row: usize) -> Option<S> {
+        let index = self.flat_index(col, row, self.dims.0 + 1)?;
+        Some(self.values[index])
+    }
+
+    /// Mutably references the value of the neuron at a column and row.
+    pub fn value_at_mut(&mut self, col: usize, row: usize) -> Option<&mut S> {
+        let index = self.flat_index(col, row, self.dims.0 + 1)?;
+        Some(&mut self.values[index])
+    }
+
+    /// Reads the firing strength of the neuron at a column and row.
+    pub fn strength_at(&self, col: usize, row: usize) -> Option<S> {
+        let index = self.flat_index(col, row, self.dims.0)?;
+        Some(self.strengths[self.param_index(index)])
+    }
+
+    /// Mutably references the firing strength of the neuron at a column and
+    /// row.
+    pub fn strength_at_mut(&mut self, col: usize, row: usize) -> Option<&mut S> {
+        let index = self.flat_index(col, row, self.dims.0)?;
+        let index = self.param_index(index);
+        Some(&mut self.strengths[index])
+    }
+
+    /// Reads the firing threshold of the neuron at a column and row.
+    pub fn threshold_at(&self, col: usize, row: usize) -> Option<S> {
+        let index = self.flat_index(col, row, self.dims.0)?;
+        Some(self.thresholds[self.param_index(index)])
+    }
+
+    /// Mutably references the firing threshold of the neuron at a column and
+    /// row.
+    pub fn threshold_at_mut(&mut self, col: usize, row: usize) -> Option<&mut S> {
+        let index = self.flat_index(col, row, self.dims.0)?;
+        let index = self.param_index(index);
+        Some(&mut self.thresholds[index])
+    }
+
+    /// Returns the pattern this Lobe's neurons are connected in.
+    pub fn connectivity(&self) -> Connectivity {
+        self.connectivity
+    }
+
+    /// Translates a neuron's position and one of its weight slots into the
+    /// flat index of the neighbor that weight connects to, or `None` if the
+    /// neighbor would fall outside the Lobe.
+    fn neighbor_index(&self, col: usize, row: usize, slot: usize) -> Option<usize> {
+        let (col_offset, row_offset) = self.connectivity.offset(slot);
+        let col = col.checked_add_signed(col_offset)?;
+        let row = row.checked_add_signed(row_offset)?;
+
+        self.flat_index(col, row, self.dims.0 + 1)
+    }
+
+    /// Translates a forward connection, from the neuron at `col` and
+    /// `from_row` to the neuron at the next column and `to_row`, into the
+    /// flat index of its weight, or `None` if the two are not connected.
+    /// While tied, the weight is in the template column.
+    fn weight_index_between(&self, col: usize, from_row: usize, to_row: usize) -> Option<usize> {
+        let source = self.param_index(self.flat_index(col, from_row, self.dims.0)?);
+        let row_offset = to_row as isize - from_row as isize;
+
+        if to_row >= self.dims.1 {
+            return None;
+        }
+
+        let neighbors = self.connectivity.neighbors();
+        let slot =
+            (0..neighbors).find(|slot| self.connectivity.offset(*slot) == (1, row_offset))?;
+
+        Some(source * neighbors + slot)
+    }
+
+    /// Reads the weight of the forward connection from the neuron at `col`
+    /// and `from_row` to the neuron at the next column and `to_row`, or
+    /// `None` if the two are not connected.
+    pub fn weight_between(&self, col: usize, from_row: usize, to_row: usize) -> Option<S> {
+        let index = self.weight_index_between(col, from_row, to_row)?;
+        Some(self.weights[index])
+    }
+
+    /// Mutably references the weight of the forward connection from the
+    /// neuron at `col` and `from_row` to the neuron at the next column and
+    /// `to_row`, or `None` if the two are not connected.
+    pub fn weight_between_mut(
+        &mut self,
+        col: usize,
+        from_row: usize,
+        to_row: usize,
+    ) -> Option<&mut S> {
+        let index = self.weight_index_between(col, from_row, to_row)?;
+        Some(&mut self.weights[index])
+    }
+
+    /// Reads the weight of the forward connection from the neuron at `col`
+    /// and `row` to its neighbor at `offset` in the next column, or `None` if
+    /// there is no such neighbor, such as past the edge of the Lobe.
+    pub fn neighbor_weight(&self, col: usize, row: usize, offset: NeighborOffset) -> Option<S> {
+        let to_row = row.checked_add_signed(offset.row_offset())?;
+        self.weight_between(col, row, to_row)
+    }
+
+    /// Mutably references the weight of the forward connection from the
+    /// neuron at `col` and `row` to its neighbor at `offset` in the next
+    /// column, or `None` if there is no such neighbor.
+    pub fn neighbor_weight_mut(
+        &mut self,
+        col: usize,
+        row: usize,
+        offset: NeighborOffset,
+    ) -> Option<&mut S> {
+        let to_row = row.checked_add_signed(offset.row_offset())?;
+        self.weight_between_mut(col, row, to_row)
     }
 
-    /// References a column of the Lobe's forward weights.
-    pub fn weight_column_ref(&self, which: usize) -> &[Amount] {
-        &self.weights[which * 3 * self.dims.1..(which + 1) * 3 * self.dims.1]
+    /// References a column of the Lobe's forward weights. While tied, every
+    /// column references the template; see [Lobe::set_tied].
+    pub fn weight_column_ref(&self, which: usize) -> &[S] {
+        let which = self.param_column(which);
+        let column = self.connectivity.neighbors() * self.dims.1;
+        &self.weights[which * column..(which + 1) * column]
     }
 
     /// Mutably references a column of the Lobe's forward weights.
-    pub fn weight_column_mut(&mut self, which: usize) -> &mut [Amount] {
-        &mut self.weights[which * 3 * self.dims.1..(which + 1) * 3 * self.dims.1]
+    pub fn weight_column_mut(&mut self, which: usize) -> &mut [S] {
+        let which = self.param_column(which);
+        let column = self.connectivity.neighbors() * self.dims.1;
+        &mut self.weights[which * column..(which + 1) * column]
     }
 
-    /// Iterates on the columns of the Lobe's forward weights.
-    pub fn weight_column_chunks(&self, which: usize) -> std::slice::Chunks<Amount> {
-        self.weight_column_ref(which).chunks(3)
+    /// Iterates on the columns of the Lobe's forward weights, one chunk of
+    /// [Connectivity::neighbors] weights per neuron.
+    pub fn weight_column_chunks(&self, which: usize) -> core::slice::Chunks<'_, S> {
+        self.weight_column_ref(which)
+            .chunks(self.connectivity.neighbors())
     }
 
-    /// Returns mutable slices into all parameters, useful for training.
-    pub fn all_parameters_slices<'a>(&'a mut self) -> Vec<&'a mut [Amount]> {
-        vec![
-            &mut self.weights,
-            &mut self.thresholds,
-            &mut self.strengths,
-            slice::from_mut(&mut self.falloff),
-        ]
+    /// Returns mutable slices into all trainable parameters, useful for
+    /// training.
+    ///
+    /// Groups frozen with [Lobe::freeze] are left out. The per-neuron
+    /// falloffs and biases are only included if they are set. While tied,
+    /// only the template column's weights, thresholds and strengths are
+    /// included, which ticks read for every column.
+    pub fn all_parameters_slices(&mut self) -> Vec<&mut [S]> {
+        let trainable = self.trainable;
+        let template = self.template_len();
+        let neighbors = self.connectivity.neighbors();
+        let mut slices: Vec<&mut [S]> = vec![];
+
+        if trainable.weights {
+            slices.push(&mut self.weights[..template * neighbors]);
+        }
+
+        if trainable.thresholds {
+            slices.push(&mut self.thresholds[..template]);
+        }
+
+        if trainable.strengths {
+            slices.push(&mut self.strengths[..template]);
+        }
+
+        if trainable.falloff {
+            slices.push(slice::from_mut(&mut self.falloff));
+        }
+
+        if trainable.reset {
+            slices.push(slice::from_mut(&mut self.reset_value));
+        }
+
+        if let (true, Some(falloffs)) = (trainable.falloff, &mut self.falloff_per_neuron) {
+            slices.push(falloffs);
+        }
+
+        if let (true, Some(bias)) = (trainable.bias, &mut self.bias) {
+            slices.push(bias);
+        }
+
+        slices
     }
 
     /// Returns owned vector copies of all parameters, useful for training.
-    pub fn all_parameters_owned(&self) -> Vec<Amount> {
+    ///
+    /// The thresholds, weights and strengths come first, then the shared
+    /// falloff and the reset value. The per-neuron falloffs follow if they
+    /// are set, then the biases if they are set. A Lobe with biases but no
+    /// per-neuron falloffs repeats its shared falloff for every neuron, so
+    /// the layout stays unambiguous.
+    ///
+    /// While tied, only the template column's thresholds, weights and
+    /// strengths are included, since the other columns share them.
+    pub fn all_parameters_owned(&self) -> Vec<S> {
+        let template = self.template_len();
+        let neighbors = self.connectivity.neighbors();
         let mut vec = vec![];
 
-        vec.extend(&self.thresholds);
-        vec.extend(&self.weights);
-        vec.extend(&self.strengths);
+        vec.extend(&self.thresholds[..template]);
+        vec.extend(&self.weights[..template * neighbors]);
+        vec.extend(&self.strengths[..template]);
         vec.push(self.falloff);
+        vec.push(self.reset_value);
+
+        match (&self.falloff_per_neuron, &self.bias) {
+            (Some(falloffs), _) => vec.extend(falloffs),
+            (None, Some(_)) => vec.extend(vec![self.falloff; self.values.len()]),
+            (None, None) => {}
+        }
+
+        if let Some(bias) = &self.bias {
+            vec.extend(bias);
+        }
 
         vec
     }
 
+    /// Takes a gradient descent step: subtracts `lr * grad[i]` from every
+    /// parameter, in the same order as [Lobe::all_parameters_owned].
+    ///
+    /// Entries for groups frozen with [Lobe::freeze] are skipped. A Lobe with
+    /// biases but no per-neuron falloffs gets per-neuron falloffs, since the
+    /// layout has one falloff entry per neuron. Nothing is changed if the
+    /// gradient does not have one entry per parameter.
+    pub fn apply_gradient(&mut self, grad: &[S], lr: S) -> Result<(), SizeError> {
+        let expected = self.all_parameters_owned().len();
+
+        if grad.len() != expected {
+            return Err(SizeError {
+                expected,
+                got: grad.len(),
+            });
+        }
+
+        if self.bias.is_some() && self.falloff_per_neuron.is_none() {
+            self.falloff_per_neuron = Some(vec![self.falloff; self.values.len()]);
+        }
+
+        let trainable = self.trainable;
+        let template = self.template_len();
+        let neighbors = self.connectivity.neighbors();
+        let mut grad = grad.iter();
+        let mut descend = |params: &mut [S], trainable: bool| {
+            for (param, grad) in izip!(params, &mut grad) {
+                if trainable {
+                    *param -= lr * *grad;
+                }
+            }
+        };
+
+        descend(&mut self.thresholds[..template], trainable.thresholds);
+        descend(&mut self.weights[..template * neighbors], trainable.weights);
+        descend(&mut self.strengths[..template], trainable.strengths);
+        descend(slice::from_mut(&mut self.falloff), trainable.falloff);
+        descend(slice::from_mut(&mut self.reset_value), trainable.reset);
+
+        if let Some(falloffs) = &mut self.falloff_per_neuron {
+            descend(falloffs, trainable.falloff);
+        }
+
+        if let Some(bias) = &mut self.bias {
+            descend(bias, trainable.bias);
+        }
+
+        self.sync_tied();
+
+        Ok(())
+    }
+
+    /// Returns every parameter as a float, in the same order as
+    /// [Lobe::all_parameters_owned].
+    ///
+    /// Handy for float-based optimizers; tuned values can be fed back
+    /// through [Lobe::from_parameters] after converting them with
+    /// [Scalar::from_f64].
+    pub fn parameters_as_f64(&self) -> Vec<f64> {
+        self.all_parameters_owned()
+            .into_iter()
+            .map(Scalar::to_f64)
+            .collect()
+    }
+
+    /// Returns the output column's values as floats.
+    pub fn output_as_f64(&self) -> Vec<f64> {
+        self.get_output()
+            .iter()
+            .map(|value| value.to_f64())
+            .collect()
+    }
+
+    /// Whether another Lobe has the same dimensions and connectivity, and
+    /// every parameter, in the order of [Lobe::all_parameters_owned], within
+    /// `tol` of this Lobe's.
+    ///
+    /// Unlike `==`, transient state such as values is not compared.
+    pub fn approx_eq(&self, other: &Lobe<S>, tol: S) -> bool {
+        if self.dims != other.dims || self.connectivity != other.connectivity {
+            return false;
+        }
+
+        let (left, right) = (self.all_parameters_owned(), other.all_parameters_owned());
+        let tol = tol.to_f64();
+
+        left.len() == right.len()
+            && izip!(left, right).all(|(left, right)| (left.to_f64() - right.to_f64()).abs() <= tol)
+    }
+
     /// Returns the dimensions of this Lobe, useful for training.
     pub fn get_dims(&self) -> (usize, usize) {
         self.dims
     }
 
-    /// Creates a new Lobe from a list of parameters, loaded in the same order
-    /// they would be concatenated in [all_parameters_owned].
-    pub fn from_parameters(dims: (usize, usize), params: &[Amount]) -> Self {
-        let area = dims.0 * dims.1;
+    /// Returns the rate at which this Lobe's values decay.
+    pub fn falloff(&self) -> S {
+        self.falloff
+    }
 
-        debug_assert!(params.len() == area * 5 + 1);
+    /// Sets the rate at which this Lobe's values decay.
+    ///
+    /// Negative values would make the decay pass add energy instead, so they
+    /// are clamped to zero.
+    pub fn set_falloff(&mut self, value: S) {
+        self.falloff = if value < S::ZERO { S::ZERO } else { value };
+    }
 
-        Self {
-            dims,
-            thresholds: params[0..area].to_vec(),
-            weights: params[area..area * 4].to_vec(),
-            strengths: params[area * 4..area * 5].to_vec(),
-            falloff: *params.last().unwrap(),
-            values: vec![Amount::from_num(0); area + dims.0],
+    /// References the per-neuron falloff rates, if they are set.
+    pub fn falloff_per_neuron(&self) -> Option<&[S]> {
+        self.falloff_per_neuron.as_deref()
+    }
+
+    /// Sets a falloff rate for every neuron, overriding the shared falloff,
+    /// or clears them with `None`.
+    ///
+    /// There is one rate per value, including the output column. Negative
+    /// rates are clamped to zero, as in [Lobe::set_falloff].
+    pub fn set_falloff_per_neuron(&mut self, falloffs: Option<Vec<S>>) -> Result<(), ParamError> {
+        if let Some(falloffs) = &falloffs {
+            if falloffs.len() != self.values.len() {
+                return Err(ParamError::LengthMismatch {
+                    expected: self.values.len(),
+                    got: falloffs.len(),
+                });
+            }
         }
+
+        self.falloff_per_neuron = falloffs.map(|mut falloffs| {
+            for falloff in &mut falloffs {
+                if *falloff < S::ZERO {
+                    *falloff = S::ZERO;
+                }
+            }
+
+            falloffs
+        });
+
+        Ok(())
     }
-}
 
-impl NeuralObject for Lobe {
-    fn input_size(&self) -> usize {
-        self.dims.1
+    /// References the constant drive added to every neuron on each tick, if
+    /// it is set.
+    pub fn bias(&self) -> Option<&[S]> {
+        self.bias.as_deref()
+    }
+
+    /// Sets a constant drive for every neuron, or clears it with `None`.
+    ///
+    /// There is one bias per value, including the output column. Each is
+    /// added to its neuron's value at the start of every tick, before
+    /// anything fires, so a positive bias can make a neuron fire on its own.
+    pub fn set_bias(&mut self, bias: Option<Vec<S>>) -> Result<(), ParamError> {
+        if let Some(bias) = &bias {
+            if bias.len() != self.values.len() {
+                return Err(ParamError::LengthMismatch {
+                    expected: self.values.len(),
+                    got: bias.len(),
+                });
+            }
+        }
+
+        self.bias = bias;
+
+        Ok(())
     }
 
-    fn apply_input(&mut self, inputs: &[Amount]) {
-        self.value_column_mut(0)
-            .iter_mut()
-            .zip(inputs)
-            .for_each(|(into, from)| *into += *from)
+    /// References the per-channel offsets subtracted from inputs, if set.
+    pub fn input_offset(&self) -> Option<&[S]> {
+        self.input_offset.as_deref()
     }
 
-    fn tick(&mut self, duration_secs: f64) {
-        let duration_secs = Amount::from_num(duration_secs);
-        let breadth = self.dims.1;
-        let area = self.dims.1 * self.dims.0;
+    /// References the per-channel factors inputs are scaled by, if set.
+    pub fn input_scale(&self) -> Option<&[S]> {
+        self.input_scale.as_deref()
+    }
 
-        let mut outputs = vec![Amount::from_num(0.0); area];
+    /// Sets a per-channel offset to subtract from every input, or clears it
+    /// with `None`, so that inputs of different scales can be normalized
+    /// without preprocessing them; see [Lobe::set_input_scale].
+    ///
+    /// There is one offset per input neuron. Inputs to the input column,
+    /// through [NeuralObject::apply_input] and [Lobe::apply_input_at],
+    /// become `(input - offset) * scale` before they are added.
+    pub fn set_input_offset(&mut self, offset: Option<Vec<S>>) -> Result<(), ParamError> {
+        Self::check_input_channels(self.dims.1, &offset)?;
+        self.input_offset = offset;
 
-        for (value_source, weights, strengths, thresholds, value_sink) in izip!(
-            self.values_chunked(),
-            self.weights.chunks(self.dims.1 * 3),
-            self.strengths_chunked(),
-            self.thresholds_chunked(),
-            outputs.chunks_mut(breadth),
-        ) {
-            for offset in 0..=2 {
-                let to_skip_output = (offset as isize - 1).max(0) as usize;
-                let to_skip_input = (1 - offset as isize).max(0) as usize;
+        Ok(())
+    }
 
-                let weights_iter = weights.chunks(3).skip(to_skip_input);
+    /// Sets a per-channel factor to scale every input by, after subtracting
+    /// the offset, or clears it with `None`; see [Lobe::set_input_offset].
+    pub fn set_input_scale(&mut self, scale: Option<Vec<S>>) -> Result<(), ParamError> {
+        Self::check_input_channels(self.dims.1, &scale)?;
+        self.input_scale = scale;
 
-                for (input, weight_chunk, strength, threshold, output) in izip!(
-                    value_source.iter().skip(to_skip_input),
-                    weights_iter,
-                    strengths.iter().skip(to_skip_input),
-                    thresholds.iter().skip(to_skip_input),
-                    value_sink.iter_mut().skip(to_skip_output),
-                ) {
-                    let weight = weight_chunk[offset];
+        Ok(())
+    }
 
-                    *output += if *input < *threshold {
-                        Amount::from_num(0)
-                    } else {
-                        input * weight * strength * duration_secs
-                    };
-                }
-            }
+    /// Checks that a per-channel input setting has one entry per input
+    /// neuron.
+    fn check_input_channels(breadth: usize, channels: &Option<Vec<S>>) -> Result<(), ParamError> {
+        match channels {
+            Some(channels) if channels.len() != breadth => Err(ParamError::LengthMismatch {
+                expected: breadth,
+                got: channels.len(),
+            }),
+            _ => Ok(()),
         }
+    }
 
-        for (value, threshold) in izip!(&mut self.values, &self.thresholds) {
-            if *value >= *threshold {
-                *value = Amount::from_num(0);
-            }
+    /// Applies the input offset and scale of the given channel, if set.
+    fn normalize_input(
+        offset: &Option<Vec<S>>,
+        scale: &Option<Vec<S>>,
+        channel: usize,
+        input: S,
+    ) -> S {
+        let input = match offset {
+            Some(offset) => input - offset[channel],
+            None => input,
+        };
+
+        match scale {
+            Some(scale) => input * scale[channel],
+            None => input,
         }
+    }
+
+    /// Returns the rate at which rewards change the forward weights.
+    pub fn learning_rate(&self) -> S {
+        self.learning_rate
+    }
+
+    /// Sets the rate at which rewards change the forward weights.
+    pub fn set_learning_rate(&mut self, value: S) {
+        self.learning_rate = value;
+    }
+
+    /// Returns the fraction of every eligibility trace that fades each tick.
+    pub fn trace_decay(&self) -> S {
+        self.trace_decay
+    }
+
+    /// Sets the fraction of every eligibility trace that fades each tick.
+    ///
+    /// Each connection keeps a trace of how recently its source fired while
+    /// its target was active, and rewards are scaled by it, so a reward
+    /// delivered a few ticks late still reaches the connections responsible.
+    /// The default of one forgets everything after a tick, so only the most
+    /// recent tick is rewarded; smaller rates remember for longer.
+    pub fn set_trace_decay(&mut self, value: S) {
+        self.trace_decay = value;
+    }
+
+    /// References every connection's eligibility trace, laid out like the
+    /// forward weights.
+    pub fn eligibility(&self) -> &[S] {
+        &self.eligibility
+    }
+
+    /// Returns how many ticks a neuron stays silent for after firing.
+    pub fn refractory_ticks(&self) -> u32 {
+        self.refractory_ticks
+    }
+
+    /// Sets how many ticks a neuron stays silent for after firing.
+    ///
+    /// While refractory, a neuron is held at zero and ignores both external
+    /// input and propagation from the previous column. A neuron that fires
+    /// on one tick sits out the next `ticks` ticks in full.
+    pub fn set_refractory_ticks(&mut self, ticks: u32) {
+        self.refractory_ticks = ticks;
+    }
 
-        for (into, from) in izip!(&mut self.values[breadth..], &outputs) {
-            *into += *from;
+    /// Returns this Lobe with the given refractory period.
+    pub fn with_refractory_ticks(mut self, ticks: u32) -> Self {
+        self.set_refractory_ticks(ticks);
+        self
+    }
+
+    /// Returns the lowest value a neuron can be pushed down to.
+    pub fn min_value(&self) -> S {
+        self.min_value
+    }
+
+    /// Sets the lowest value a neuron can be pushed down to.
+    ///
+    /// Inhibitory (negative) weights subtract from their targets, so without
+    /// a floor a strongly inhibited neuron could be driven arbitrarily far
+    /// below rest. Defaults to zero. A floor above [Lobe::reset_value] holds
+    /// neurons that just fired at the floor instead.
+    pub fn set_min_value(&mut self, value: S) {
+        self.min_value = value;
+    }
+
+    /// Returns the value neurons are reset to after firing.
+    pub fn reset_value(&self) -> S {
+        self.reset_value
+    }
+
+    /// Sets the value neurons are reset to after firing, and held at for
+    /// their refractory period. Defaults to zero.
+    ///
+    /// A negative reset models after-hyperpolarization: a neuron that just
+    /// fired needs extra input to fire again, until its value leaks back up
+    /// towards zero. Values are clamped to [Lobe::min_value], so it is
+    /// lowered to the reset value if above it.
+    pub fn set_reset_value(&mut self, value: S) {
+        self.reset_value = value;
+
+        if self.min_value > value {
+            self.min_value = value;
         }
+    }
 
-        for value in &mut self.values {
-            *value -= *value * self.falloff * duration_secs;
+    /// Returns the magnitude below which values are zeroed after every tick,
+    /// if any.
+    pub fn denoise_epsilon(&self) -> Option<S> {
+        self.denoise_epsilon
+    }
+
+    /// Zeroes every value smaller in magnitude than `epsilon` at the end of
+    /// every tick, as with [Lobe::clear_below], or stops with `None`, the
+    /// default.
+    ///
+    /// Leaking leaves tiny remnants behind that never quite reach zero; this
+    /// clears them before they accumulate into drift.
+    pub fn set_denoise_epsilon(&mut self, epsilon: Option<S>) {
+        self.denoise_epsilon = epsilon;
+    }
+
+    /// Returns how strongly the output column feeds back into the input
+    /// column.
+    pub fn recurrent_gain(&self) -> S {
+        self.recurrent_gain
+    }
+
+    /// Sets how strongly the output column feeds back into the input column.
+    ///
+    /// At the start of every tick, each output value times this gain is added
+    /// to the input neuron in the same row, so activity can persist after
+    /// external input stops. A gain of zero, the default, disables feedback.
+    ///
+    /// The loop amplifies itself whenever the gain, combined with the weights
+    /// along the way, exceeds what the falloff leaks each tick; activity then
+    /// saturates instead of fading. Keep the gain small, or raise the falloff
+    /// alongside it.
+    pub fn set_recurrent_gain(&mut self, gain: S) {
+        self.recurrent_gain = gain;
+    }
+
+    /// Returns the factor every firing strength is scaled by.
+    pub fn global_gain(&self) -> S {
+        self.global_gain
+    }
+
+    /// Sets the factor every firing strength is scaled by when spikes
+    /// propagate, to tune the Lobe's overall excitability without touching
+    /// each strength. Defaults to one.
+    pub fn set_global_gain(&mut self, gain: S) {
+        self.global_gain = gain;
+    }
+
+    /// Returns how strongly neurons in the same column inhibit each other.
+    pub fn lateral_inhibition(&self) -> S {
+        self.lateral_inhibition
+    }
+
+    /// Sets how strongly neurons in the same column inhibit each other.
+    ///
+    /// After every accumulation pass, the neuron with the highest value in
+    /// each column, whether or not it fires, subtracts this fraction of its
+    /// value from every other neuron in its column, so that it tends to be
+    /// the only one to fire: a soft winner-take-all. A value of zero, the
+    /// default, disables it.
+    pub fn set_lateral_inhibition(&mut self, inhibition: S) {
+        self.lateral_inhibition = inhibition;
+    }
+
+    /// References how many times each neuron has fired since the counts were
+    /// last reset.
+    ///
+    /// Counts are laid out column by column, like the values. Neurons in the
+    /// output column have no threshold and never fire, so only the first
+    /// `dims.0` columns are counted.
+    pub fn spike_counts(&self) -> &[u64] {
+        &self.spike_counts
+    }
+
+    /// Returns how many ticks have passed since the spike counts were last
+    /// reset.
+    pub fn counted_ticks(&self) -> u64 {
+        self.counted_ticks
+    }
+
+    /// Sets the simulated time back to zero.
+    pub fn reset_elapsed_secs(&mut self) {
+        self.elapsed = Clock::default();
+    }
+
+    /// Returns the smoothed firing rate of every neuron, in spikes per tick,
+    /// laid out like [Lobe::spike_counts], or an empty slice if rates are
+    /// not being tracked; see [Lobe::set_rate_alpha].
+    pub fn firing_rates(&self) -> &[S] {
+        &self.firing_rates
+    }
+
+    /// Returns the smoothing factor of the firing rates, if they are being
+    /// tracked.
+    pub fn rate_alpha(&self) -> Option<S> {
+        self.rate_alpha
+    }
+
+    /// Starts tracking a smoothed firing rate for every neuron, or stops
+    /// with `None`, the default.
+    ///
+    /// Each tick, every rate moves towards one if its neuron fired and
+    /// towards zero otherwise: `rate = rate * (1 - alpha) + fired * alpha`.
+    /// A smaller `alpha` averages over more ticks. With
+    /// [PropagationMode::Full], only the last propagation of a tick counts.
+    /// Every rate starts back at zero.
+    pub fn set_rate_alpha(&mut self, alpha: Option<S>) {
+        self.rate_alpha = alpha;
+        self.firing_rates = match alpha {
+            Some(_) => vec![S::ZERO; self.fired.len()],
+            None => vec![],
+        };
+    }
+
+    /// Moves every firing rate towards whether its neuron just fired.
+    fn update_firing_rates(&mut self, alpha: S) {
+        let keep = S::ONE - alpha;
+
+        for (rate, fired) in izip!(&mut self.firing_rates, &self.fired) {
+            *rate = *rate * keep + if *fired { alpha } else { S::ZERO };
         }
     }
 
-    fn get_output(&self) -> &[Amount] {
-        self.value_column_ref(self.dims.0)
+    /// Sets every neuron's spike count back to zero.
+    pub fn reset_spike_counts(&mut self) {
+        self.spike_counts.fill(0);
+        self.counted_ticks = 0;
     }
 
-    fn reward(&mut self, _reward: Amount) {
-        // TODO
+    /// Nudges every threshold towards keeping its neuron's firing rate at
+    /// `target_rate`, in spikes per tick, then resets the spike counts.
+    ///
+    /// Each threshold moves by `rate_lr` times the difference between its
+    /// neuron's rate since the counts were last reset and the target, so
+    /// overactive neurons become harder to fire and silent ones easier. Does
+    /// nothing if no tick has been counted yet.
+    ///
+    /// While tied, each template threshold moves by the average of what its
+    /// row's neurons call for.
+    pub fn adapt_thresholds(&mut self, target_rate: S, rate_lr: S) {
+        if self.counted_ticks == 0 {
+            return;
+        }
+
+        let ticks = self.counted_ticks as f64;
+        let rate_lr = match self.tied {
+            true => S::from_f64(rate_lr.to_f64() / self.dims.0 as f64),
+            false => rate_lr,
+        };
+
+        for (index, count) in self.spike_counts.iter().enumerate() {
+            let rate = S::from_f64(*count as f64 / ticks);
+            let param = self.param_index(index);
+
+            self.thresholds[param] += (rate - target_rate) * rate_lr;
+        }
+
+        self.sync_tied();
+        self.reset_spike_counts();
+    }
+
+    /// Returns how long each phase of the most recent tick took. With
+    /// [NeuralObject::tick_n], this covers only the last step.
+    #[cfg(feature = "profiling")]
+    pub fn last_tick_profile(&self) -> TickProfile {
+        self.last_profile.0
+    }
+
+    /// Silences every neuron that fired fewer than `min_spikes` times, by
+    /// zeroing its strength and outgoing weights, and returns how many were
+    /// pruned.
+    ///
+    /// The Lobe keeps its dimensions; pruned neurons simply stop contributing
+    /// to their neighbors. Spike counts are left as they were. While tied, a
+    /// row is pruned in every column if its neurons fired fewer than
+    /// `min_spikes` times altogether.
+    pub fn prune_inactive(&mut self, min_spikes: u64) -> usize {
+        let neighbors = self.connectivity.neighbors();
+        let template = self.template_len();
+        let mut counts = vec![0; template];
+        let mut pruned = 0;
+
+        for (index, count) in self.spike_counts.iter().enumerate() {
+            counts[self.param_index(index)] += *count;
+        }
+
+        for (count, strength, weights) in izip!(
+            &counts,
+            &mut self.strengths,
+            self.weights.chunks_mut(neighbors)
+        ) {
+            if *count < min_spikes {
+                *strength = S::ZERO;
+                weights.fill(S::ZERO);
+                pruned += 1;
+            }
+        }
+
+        self.sync_tied();
+
+        pruned * self.strengths.len() / template.max(1)
+    }
+
+    /// Returns the fraction of forward weights that are effectively zero, or
+    /// zero if the Lobe has no weights. While tied, only the template's
+    /// weights are counted.
+    pub fn sparsity(&self) -> f64 {
+        let weights = &self.weights[..self.template_len() * self.connectivity.neighbors()];
+
+        if weights.is_empty() {
+            return 0.0;
+        }
+
+        let zeros = weights
+            .iter()
+            .filter(|weight| weight.to_f64().abs() <= SPARSITY_EPSILON)
+            .count();
+
+        zeros as f64 / weights.len() as f64
+    }
+
+    /// Zeroes the smallest forward weights by magnitude until at least the
+    /// given fraction of them is zero, for magnitude-based pruning.
+    ///
+    /// Weights that are already zero count towards the fraction. While tied,
+    /// the template's weights are sparsified.
+    ///
+    /// Panics if `fraction` is not between zero and one.
+    pub fn sparsify(&mut self, fraction: f64) {
+        assert!(
+            (0.0..=1.0).contains(&fraction),
+            "sparsity must be between zero and one, got {}",
+            fraction
+        );
+
+        let template = self.template_len() * self.connectivity.neighbors();
+        let exact = fraction * template as f64;
+
+        // Rounds up by hand, since `f64::ceil` needs the standard library.
+        let count = match exact as usize {
+            count if (count as f64) < exact => count + 1,
+            count => count,
+        };
+
+        if count == 0 {
+            return;
+        }
+
+        let mut order: Vec<usize> = (0..template).collect();
+
+        order.select_nth_unstable_by(count - 1, |left, right| {
+            let magnitude = |index: &usize| self.weights[*index].to_f64().abs();
+            magnitude(left).total_cmp(&magnitude(right))
+        });
+
+        for index in &order[..count] {
+            self.weights[*index] = S::ZERO;
+        }
+
+        self.sync_tied();
+    }
+
+    /// Sets every value smaller in magnitude than `epsilon` to exactly zero,
+    /// clearing sub-threshold noise. Larger values are left alone, and so is
+    /// everything if `epsilon` is not positive.
+    pub fn clear_below(&mut self, epsilon: S) {
+        if epsilon <= S::ZERO {
+            return;
+        }
+
+        // Negates the positive epsilon rather than the values, since the most
+        // negative fixed-point value has no positive counterpart.
+        let negative = -epsilon;
+
+        for value in &mut self.values {
+            if *value < epsilon && *value > negative {
+                *value = S::ZERO;
+            }
+        }
+    }
+
+    /// Rescales the weights incoming to each neuron so that, together, they
+    /// have the given L2 norm.
+    ///
+    /// Neurons without any nonzero incoming weight are left alone. Norms are
+    /// computed in floating point, so large weights can't overflow them.
+    ///
+    /// Needs the `std` feature, for its floating-point math.
+    #[cfg(feature = "std")]
+    pub fn normalize_weights(&mut self, target_l2: S) {
+        let breadth = self.dims.1;
+        let neighbors = self.connectivity.neighbors();
+        let mut norms = vec![0.0; self.values.len()];
+
+        for col in 0..self.dims.0 {
+            for row in 0..breadth {
+                for slot in 0..neighbors {
+                    if let Some(target) = self.neighbor_index(col, row, slot) {
+                        let weight = self.weights[(col * breadth + row) * neighbors + slot];
+                        norms[target] += weight.to_f64() * weight.to_f64();
+                    }
+                }
+            }
+        }
+
+        for norm in &mut norms {
+            *norm = norm.sqrt();
+        }
+
+        let target_l2 = target_l2.to_f64();
+
+        for col in 0..self.dims.0 {
+            for row in 0..breadth {
+                for slot in 0..neighbors {
+                    let target = match self.neighbor_index(col, row, slot) {
+                        Some(target) if norms[target] > 0.0 => target,
+                        _ => continue,
+                    };
+
+                    let weight = &mut self.weights[(col * breadth + row) * neighbors + slot];
+                    *weight = S::from_f64(weight.to_f64() * target_l2 / norms[target]);
+                }
+            }
+        }
+
+        self.sync_tied();
+    }
+
+    /// Clamps every threshold into the range from `min` to `max`.
+    pub fn clamp_thresholds(&mut self, min: S, max: S) {
+        for threshold in &mut self.thresholds {
+            if *threshold < min {
+                *threshold = min;
+            } else if *threshold > max {
+                *threshold = max;
+            }
+        }
+
+        self.sync_tied();
+    }
+
+    /// Replaces every forward weight with the result of a function of it,
+    /// such as rounding to a grid or shrinking towards zero.
+    pub fn map_weights(&mut self, mut f: impl FnMut(S) -> S) {
+        for weight in &mut self.weights {
+            *weight = f(*weight);
+        }
+
+        self.sync_tied();
+    }
+
+    /// Snaps every forward weight to the nearest of `levels` evenly spaced
+    /// values from `range.0` to `range.1`, both included, clamping weights
+    /// outside the range; for deploying on integer hardware.
+    ///
+    /// Returns the largest change made to any weight, to help judge how much
+    /// precision was lost. To see how that affects the output, tick a clone
+    /// taken beforehand alongside this Lobe and compare.
+    ///
+    /// Panics if `levels` is below two.
+    pub fn quantize_weights(&mut self, levels: u32, range: (S, S)) -> S {
+        assert!(
+            levels >= 2,
+            "quantizing needs at least two levels, got {}",
+            levels
+        );
+
+        let (low, high) = (range.0.to_f64(), range.1.to_f64());
+        let step = (high - low) / (levels - 1) as f64;
+        let mut max_error = S::ZERO;
+
+        self.map_weights(|weight| {
+            let position = ((weight.to_f64() - low) / step).clamp(0.0, (levels - 1) as f64);
+
+            // Rounds by hand, since `f64::round` needs the standard library.
+            let level = (position + 0.5) as u32;
+            let snapped = S::from_f64(low + level as f64 * step);
+
+            let error = if snapped > weight {
+                snapped - weight
+            } else {
+                weight - snapped
+            };
+
+            if error > max_error {
+                max_error = error;
+            }
+
+            snapped
+        });
+
+        max_error
+    }
+
+    /// Replaces every firing threshold with the result of a function of it.
+    pub fn map_thresholds(&mut self, mut f: impl FnMut(S) -> S) {
+        for threshold in &mut self.thresholds {
+            *threshold = f(*threshold);
+        }
+
+        self.sync_tied();
+    }
+
+    /// Replaces every firing strength with the result of a function of it.
+    pub fn map_strengths(&mut self, mut f: impl FnMut(S) -> S) {
+        for strength in &mut self.strengths {
+            *strength = f(*strength);
+        }
+
+        self.sync_tied();
+    }
+
+    /// Adds to the value of a single neuron in the input column, like a
+    /// sparse [NeuralObject::apply_input].
+    ///
+    /// Panics if `row` is not below the Lobe's breadth.
+    pub fn apply_input_at(&mut self, row: usize, amount: S) {
+        assert!(
+            row < self.dims.1,
+            "input row {} is out of range for a Lobe of breadth {}",
+            row,
+            self.dims.1
+        );
+
+        if self.refractory.get(row).is_none_or(|c| *c == 0) {
+            self.values[row] +=
+                Self::normalize_input(&self.input_offset, &self.input_scale, row, amount);
+        }
+    }
+
+    /// Like [Lobe::apply_input_at], but fails without applying anything if
+    /// `row` is not below the Lobe's breadth.
+    pub fn try_apply_input_at(&mut self, row: usize, amount: S) -> Result<(), SpikerError> {
+        self.check_input_row(row)?;
+        self.apply_input_at(row, amount);
+
+        Ok(())
+    }
+
+    /// Checks that an input row is below the Lobe's breadth.
+    fn check_input_row(&self, row: usize) -> Result<(), SpikerError> {
+        match row < self.dims.1 {
+            true => Ok(()),
+            false => Err(SpikerError::IndexOutOfRange {
+                index: row,
+                len: self.dims.1,
+            }),
+        }
+    }
+
+    /// Adds a list of `(row, amount)` events to the input column, skipping
+    /// zero amounts entirely. Cheaper than [NeuralObject::apply_input] when
+    /// most inputs are zero.
+    ///
+    /// Panics, before applying anything, if any row is not below the Lobe's
+    /// breadth.
+    pub fn apply_sparse_input(&mut self, events: &[(usize, S)]) {
+        if let Some((row, _)) = events.iter().find(|(row, _)| *row >= self.dims.1) {
+            panic!(
+                "input row {} is out of range for a Lobe of breadth {}",
+                row, self.dims.1
+            );
+        }
+
+        for (row, amount) in events {
+            if *amount != S::ZERO {
+                self.apply_input_at(*row, *amount);
+            }
+        }
+    }
+
+    /// Like [Lobe::apply_sparse_input], but fails without applying anything
+    /// if any row is not below the Lobe's breadth.
+    pub fn try_apply_sparse_input(&mut self, events: &[(usize, S)]) -> Result<(), SpikerError> {
+        for (row, _) in events {
+            self.check_input_row(*row)?;
+        }
+
+        self.apply_sparse_input(events);
+
+        Ok(())
+    }
+
+    /// Adds inputs to a range of rows of any column, rather than only the
+    /// input column, so that several sources can feed different regions of
+    /// the Lobe.
+    ///
+    /// Inputs are matched up with the rows in order, and any excess is
+    /// ignored, like [NeuralObject::apply_input]. Refractory neurons are
+    /// skipped.
+    ///
+    /// Panics if `col` is past the output column, or if `row_range` does not
+    /// lie within the Lobe's breadth.
+    pub fn apply_input_to_region(&mut self, col: usize, row_range: Range<usize>, inputs: &[S]) {
+        let (width, breadth) = self.dims;
+
+        assert!(
+            col <= width,
+            "input column {} is out of range for a Lobe of width {}",
+            col,
+            width
+        );
+
+        assert!(
+            row_range.start <= row_range.end && row_range.end <= breadth,
+            "input rows {:?} are out of range for a Lobe of breadth {}",
+            row_range,
+            breadth
+        );
+
+        let start = col * breadth + row_range.start;
+        let end = col * breadth + row_range.end;
+
+        for (index, (into, from)) in izip!(&mut self.values[start..end], inputs).enumerate() {
+            if self.refractory.get(start + index).is_none_or(|c| *c == 0) {
+                *into += *from;
+            }
+        }
+    }
+
+    /// Like [Lobe::apply_input_to_region], but fails without applying
+    /// anything if `col` is past the output column, or if `row_range` does
+    /// not lie within the Lobe's breadth.
+    pub fn try_apply_input_to_region(
+        &mut self,
+        col: usize,
+        row_range: Range<usize>,
+        inputs: &[S],
+    ) -> Result<(), SpikerError> {
+        let (width, breadth) = self.dims;
+
+        if col > width {
+            return Err(SpikerError::IndexOutOfRange {
+                index: col,
+                len: width + 1,
+            });
+        }
+
+        if row_range.start > row_range.end || row_range.end > breadth {
+            return Err(SpikerError::IndexOutOfRange {
+                index: row_range.end.max(row_range.start),
+                len: breadth,
+            });
+        }
+
+        self.apply_input_to_region(col, row_range, inputs);
+
+        Ok(())
+    }
+
+    /// Returns which output neurons are currently spiking, as opposed to
+    /// their raw values from [NeuralObject::get_output].
+    ///
+    /// The output column has no thresholds and is never reset by `tick`, so
+    /// rather than being captured mid-tick, spikes are recomputed from the
+    /// current values: any positive output value counts as a spike.
+    pub fn output_spikes(&self) -> Vec<bool> {
+        let start = self.dims.0 * self.dims.1;
+
+        (start..start + self.dims.1)
+            .map(|index| self.is_active(index))
+            .collect()
+    }
+
+    /// Returns the sum of every neuron's current value, including the output
+    /// column, saturating if it is out of range.
+    pub fn total_activation(&self) -> S {
+        S::from_f64(self.values.iter().map(|value| value.to_f64()).sum())
+    }
+
+    /// Counts the neurons that are currently at or above their threshold.
+    /// Output neurons count when their value is positive, as in
+    /// [Lobe::output_spikes].
+    pub fn active_neuron_count(&self) -> usize {
+        (0..self.values.len())
+            .filter(|index| self.is_active(*index))
+            .count()
+    }
+
+    /// Returns whether each neuron is currently at or above its threshold,
+    /// laid out column by column like the values, for raster plots.
+    /// Output neurons count when their value is positive, as in
+    /// [Lobe::output_spikes].
+    pub fn fire_raster(&self) -> Vec<bool> {
+        (0..self.values.len())
+            .map(|index| self.is_active(index))
+            .collect()
+    }
+
+    /// Whether a neuron is currently active, by flat index into the values.
+    ///
+    /// Neurons in the output column have no threshold, so any positive value
+    /// counts as activity there.
+    fn is_active(&self, index: usize) -> bool {
+        match self.thresholds.get(self.param_index(index)) {
+            Some(threshold) => self.values[index] >= *threshold,
+            None => self.values[index] > S::ZERO,
+        }
+    }
+
+    /// Computes what every neuron receives from its firing neighbors, writing
+    /// it into `outputs`, which holds one value per neuron.
+    fn propagate(&self, outputs: &mut [S::Wide], duration_secs: S) {
+        #[cfg(feature = "parallel")]
+        match threads::jobs(self.values.len()) {
+            1 => {}
+            jobs => return self.propagate_parallel(outputs, duration_secs, jobs),
+        }
+
+        self.propagate_columns(0, outputs, duration_secs);
+    }
+
+    /// Like [Lobe::propagate], but handing runs of columns to `jobs`
+    /// threads, since every target column only writes into its own chunk of
+    /// `outputs`.
+    ///
+    /// The threads are plain [std::thread::scope] ones rather than a rayon
+    /// pool, so the `parallel` feature adds no dependencies.
+    #[cfg(feature = "parallel")]
+    fn propagate_parallel(&self, outputs: &mut [S::Wide], duration_secs: S, jobs: usize) {
+        let breadth = self.dims.1.max(1);
+        let columns_per_job = (self.dims.0 + 1).div_ceil(jobs).max(1);
+
+        std::thread::scope(|scope| {
+            for (job, sink) in outputs.chunks_mut(columns_per_job * breadth).enumerate() {
+                scope.spawn(move || {
+                    self.propagate_columns(job * columns_per_job, sink, duration_secs)
+                });
+            }
+        });
+    }
+
+    /// Gathers what a run of target columns, starting at `first_col`,
+    /// receives from their firing neighbors into `sink`, in [Scalar::Wide].
+    ///
+    /// Each firing neuron contributes `transfer(value) * weight * strength *
+    /// gain * duration` to its targets, with the Lobe's global gain. Positive
+    /// weights are excitatory and negative weights are inhibitory,
+    /// subtracting from the target's value.
+    fn propagate_columns(&self, first_col: usize, sink: &mut [S::Wide], duration_secs: S) {
+        let breadth = self.dims.1;
+        let neighbors = self.connectivity.neighbors();
+        let mode = self.overflow_mode;
+        let scale = mode.mul(self.global_gain.widen(), duration_secs.widen());
+
+        if breadth == 0 {
+            return;
+        }
+
+        for (target_col, value_sink) in (first_col..).zip(sink.chunks_mut(breadth)) {
+            for slot in 0..neighbors {
+                let (col_offset, row_offset) = self.connectivity.offset(slot);
+
+                let source_col = match target_col.checked_add_signed(-col_offset) {
+                    Some(col) if col < self.dims.0 => col,
+                    _ => continue,
+                };
+
+                let to_skip_output = row_offset.max(0) as usize;
+                let to_skip_input = (-row_offset).max(0) as usize;
+
+                let start = source_col * breadth;
+                let end = start + breadth;
+                let param_start = if self.tied { 0 } else { start };
+                let param_end = param_start + breadth;
+
+                for (input, weight_chunk, strength, fired, output) in izip!(
+                    self.values[start..end].iter().skip(to_skip_input),
+                    self.weights[param_start * neighbors..param_end * neighbors]
+                        .chunks(neighbors)
+                        .skip(to_skip_input),
+                    self.strengths[param_start..param_end]
+                        .iter()
+                        .skip(to_skip_input),
+                    self.fired[start..end].iter().skip(to_skip_input),
+                    value_sink.iter_mut().skip(to_skip_output),
+                ) {
+                    let weight = weight_chunk[slot].widen();
+
+                    if *fired {
+                        let input = self.transfer.apply(*input).widen();
+                        let spike = mode.mul(mode.mul(input, weight), strength.widen());
+                        *output = mode.add(*output, mode.mul(spike, scale));
+                    }
+                }
+            }
+        }
+    }
+
+    /// Leaks every value towards zero at the falloff rate.
+    fn decay(&mut self, duration_secs: S) {
+        #[cfg(feature = "parallel")]
+        match threads::jobs(self.values.len()) {
+            1 => {}
+            jobs => return self.decay_parallel(duration_secs, jobs),
+        }
+
+        match &self.falloff_per_neuron {
+            None => S::decay(&mut self.values, self.falloff, duration_secs),
+            Some(falloffs) => Self::decay_each(&mut self.values, falloffs, duration_secs),
+        }
+    }
+
+    /// Leaks every value towards zero at the falloff rate, splitting the
+    /// values between `jobs` threads.
+    #[cfg(feature = "parallel")]
+    fn decay_parallel(&mut self, duration_secs: S, jobs: usize) {
+        let per_job = self.values.len().div_ceil(jobs).max(1);
+        let falloff = self.falloff;
+
+        std::thread::scope(|scope| match &self.falloff_per_neuron {
+            None => {
+                for values in self.values.chunks_mut(per_job) {
+                    scope.spawn(move || S::decay(values, falloff, duration_secs));
+                }
+            }
+            Some(falloffs) => {
+                for (values, falloffs) in self
+                    .values
+                    .chunks_mut(per_job)
+                    .zip(falloffs.chunks(per_job))
+                {
+                    scope.spawn(move || Self::decay_each(values, falloffs, duration_secs));
+                }
+            }
+        });
+    }
+
+    /// Leaks every value towards zero like [Lobe::decay], one at a time,
+    /// handling overflow according to `mode`.
+    fn decay_with(&mut self, mode: OverflowMode, duration_secs: S) {
+        for (index, value) in self.values.iter_mut().enumerate() {
+            let falloff = match &self.falloff_per_neuron {
+                None => self.falloff,
+                Some(falloffs) => falloffs[index],
+            };
+
+            let leak = mode.mul(mode.mul(*value, -falloff), duration_secs);
+            *value = mode.add(*value, leak);
+        }
+    }
+
+    /// Leaks every value towards zero at its own neuron's falloff rate.
+    fn decay_each(values: &mut [S], falloffs: &[S], duration_secs: S) {
+        for (value, falloff) in izip!(values, falloffs) {
+            *value -= *value * *falloff * duration_secs;
+        }
+    }
+
+    /// Copies every setting that is neither a parameter nor sized by the
+    /// dimensions from another Lobe, for Lobes built out of existing ones.
+    fn copy_settings_from(&mut self, other: &Lobe<S>) {
+        self.learning_rate = other.learning_rate;
+        self.trace_decay = other.trace_decay;
+        self.refractory_ticks = other.refractory_ticks;
+        self.min_value = other.min_value;
+        self.recurrent_gain = other.recurrent_gain;
+        self.global_gain = other.global_gain;
+        self.denoise_epsilon = other.denoise_epsilon;
+        self.lateral_inhibition = other.lateral_inhibition;
+        self.propagation_mode = other.propagation_mode;
+        self.overflow_mode = other.overflow_mode;
+        self.stochastic = other.stochastic.clone();
+        self.transfer = other.transfer;
+        self.input_offset = other.input_offset.clone();
+        self.input_scale = other.input_scale.clone();
+        self.trainable = other.trainable;
+        self.set_tied(other.tied);
+        self.set_rate_alpha(other.rate_alpha);
+        self.set_delay_waves(other.delay_waves());
+        self.learning_rule = other.learning_rule.clone();
+    }
+
+    /// Adds the output column, scaled by the recurrent gain, into the input
+    /// column, skipping refractory neurons like [NeuralObject::apply_input].
+    fn feed_back(&mut self) {
+        let breadth = self.dims.1;
+        let (inputs, rest) = self.values.split_at_mut(breadth);
+        let outputs = &rest[rest.len() - breadth..];
+
+        for (into, from, countdown) in izip!(inputs, outputs, &self.refractory) {
+            if *countdown == 0 {
+                *into += *from * self.recurrent_gain;
+            }
+        }
+    }
+
+    /// Has the neuron with the highest value in every column suppress the
+    /// other neurons of its column; see [Lobe::set_lateral_inhibition].
+    /// Refractory neurons are left at the reset value.
+    fn inhibit(&mut self) {
+        let breadth = self.dims.1;
+
+        for (values, countdowns) in izip!(
+            self.values.chunks_mut(breadth).take(self.dims.0),
+            self.refractory.chunks(breadth),
+        ) {
+            let mut winner = 0;
+
+            for (row, value) in values.iter().enumerate() {
+                if *value > values[winner] {
+                    winner = row;
+                }
+            }
+
+            let suppression = values[winner] * self.lateral_inhibition;
+
+            if suppression <= S::ZERO {
+                continue;
+            }
+
+            for (row, (value, countdown)) in izip!(values, countdowns).enumerate() {
+                if row != winner && *countdown == 0 {
+                    *value -= suppression;
+                }
+            }
+        }
+    }
+
+    /// Decays every connection's eligibility trace, then adds one to the
+    /// traces of connections whose source fired this tick and whose target
+    /// is now active.
+    fn update_eligibility(&mut self) {
+        let breadth = self.dims.1;
+        let neighbors = self.connectivity.neighbors();
+        let keep = S::ONE - self.trace_decay;
+        let mut eligibility = core::mem::take(&mut self.eligibility);
+
+        for (source, traces) in eligibility.chunks_mut(neighbors).enumerate() {
+            let (col, row) = (source / breadth, source % breadth);
+
+            for (slot, trace) in traces.iter_mut().enumerate() {
+                *trace *= keep;
+
+                if self.fired[source]
+                    && self
+                        .neighbor_index(col, row, slot)
+                        .is_some_and(|target| self.is_active(target))
+                {
+                    *trace += S::ONE;
+                }
+            }
+        }
+
+        self.eligibility = eligibility;
+    }
+
+    /// Advances a single timestep, using `outputs` as scratch space for the
+    /// propagated values. It must hold one value per neuron.
+    ///
+    /// With `v` a neuron's value, `θ` its threshold and `dt` the duration,
+    /// one step goes:
+    ///
+    /// 1. Biases and recurrent feedback are added to `v`.
+    /// 2. A neuron fires if it is not refractory and `v >= θ`, or with
+    ///    stochastic firing, at random depending on `v - θ`. This is the
+    ///    only place firing is decided.
+    /// 3. Every firing neuron sends `transfer(v) * weight * strength * gain *
+    ///    dt` to each of its neighbors, using its value from step 2.
+    /// 4. Firing neurons are reset to the reset value and become
+    ///    refractory; neurons still refractory are held at it.
+    /// 5. What each neuron received in step 3 is added to `v`, unless it is
+    ///    refractory. With lateral inhibition, the most active neuron of each
+    ///    column then suppresses the others.
+    /// 6. Steps 2 through 5 repeat, once per wave.
+    /// 7. `v` is clamped to the minimum value, then leaks towards zero:
+    ///    `v -= v * falloff * dt`.
+    /// 8. With a denoise epsilon, `v` is zeroed if smaller in magnitude.
+    ///
+    /// Steps 4 and 5 build the next values in a back buffer, reading only
+    /// the values from step 2, and swap it in once complete.
+    fn step(&mut self, duration_secs: S, outputs: &mut [S::Wide], waves: usize) {
+        let mut stopwatch = Stopwatch::start();
+
+        self.counted_ticks += 1;
+
+        if let Some(bias) = &self.bias {
+            for (index, (value, bias)) in izip!(&mut self.values, bias).enumerate() {
+                if self.refractory.get(index).is_none_or(|c| *c == 0) {
+                    *value += *bias;
+                }
+            }
+        }
+
+        // A Lobe without hidden columns has its inputs double as outputs.
+        if self.recurrent_gain != S::ZERO && self.dims.0 > 0 {
+            self.feed_back();
+        }
+
+        for _ in 0..waves {
+            self.wave(duration_secs, outputs, &mut stopwatch);
+        }
+
+        for value in &mut self.values {
+            if *value < self.min_value {
+                *value = self.min_value;
+            }
+        }
+
+        match self.overflow_mode {
+            OverflowMode::Wrap => self.decay(duration_secs),
+            mode => self.decay_with(mode, duration_secs),
+        }
+
+        if let Some(epsilon) = self.denoise_epsilon {
+            self.clear_below(epsilon);
+        }
+
+        stopwatch.lap(Phase::Decay);
+
+        self.update_eligibility();
+
+        if let Some(alpha) = self.rate_alpha {
+            self.update_firing_rates(alpha);
+        }
+
+        #[cfg(feature = "profiling")]
+        {
+            self.last_profile = profile::LastProfile(stopwatch.finish());
+        }
+    }
+
+    /// Fires, propagates and resets every neuron once, moving activity one
+    /// column along; see [Lobe::step].
+    fn wave(&mut self, duration_secs: S, outputs: &mut [S::Wide], stopwatch: &mut Stopwatch) {
+        let template = self.template_len();
+
+        for (value, threshold, fired, countdown) in izip!(
+            &self.values,
+            Self::per_neuron(&self.thresholds, template),
+            &mut self.fired,
+            &self.refractory,
+        ) {
+            *fired = *countdown == 0
+                && match &mut self.stochastic {
+                    None => *value >= *threshold,
+                    Some(stochastic) => stochastic.fires(*value - *threshold),
+                };
+        }
+
+        outputs.fill(Scalar::ZERO);
+        self.propagate(outputs, duration_secs);
+        self.delay.cycle(outputs);
+        stopwatch.lap(Phase::Propagation);
+
+        let mut next = core::mem::take(&mut self.back.0);
+        let area = self.fired.len();
+
+        next.resize(self.values.len(), S::ZERO);
+
+        for (next, value, fired, countdown, count) in izip!(
+            &mut next,
+            &self.values,
+            &self.fired,
+            &mut self.refractory,
+            &mut self.spike_counts,
+        ) {
+            *next = if *countdown > 0 {
+                self.reset_value
+            } else if *fired {
+                *countdown = self.refractory_ticks;
+                *count += 1;
+                self.reset_value
+            } else {
+                *value
+            };
+        }
+
+        // The output column never fires, so it carries over as it is.
+        next[area..].copy_from_slice(&self.values[area..]);
+
+        stopwatch.lap(Phase::Reset);
+
+        // Rounds each neuron's value once, after adding what it received.
+        let mode = self.overflow_mode;
+
+        for (index, (into, from)) in izip!(&mut next, &*outputs).enumerate() {
+            if self.refractory.get(index).is_none_or(|c| *c == 0) {
+                *into = mode.narrow(mode.add(into.widen(), *from));
+            }
+        }
+
+        // Neurons that just fired only start counting down on the next tick,
+        // so they sit out `refractory_ticks` whole ticks.
+        for (countdown, fired) in izip!(&mut self.refractory, &self.fired) {
+            if *countdown > 0 && !*fired {
+                *countdown -= 1;
+            }
+        }
+
+        core::mem::swap(&mut self.values, &mut next);
+        self.back.0 = next;
+
+        if self.lateral_inhibition != S::ZERO && self.dims.1 > 0 {
+            self.inhibit();
+        }
+
+        stopwatch.lap(Phase::Accumulation);
+    }
+
+    /// Advances several timesteps of the given number of waves each, reusing
+    /// the Lobe's scratch buffer for all of them instead of allocating.
+    ///
+    /// Panics if the duration is negative or NaN, since the values would
+    /// grow instead of decaying; a duration of zero does nothing.
+    fn tick_waves(&mut self, steps: usize, duration_secs: f64, waves: usize) {
+        assert!(
+            duration_secs >= 0.0,
+            "tick duration must not be negative, got {}",
+            duration_secs
+        );
+
+        if duration_secs == 0.0 {
+            return;
+        }
+
+        let dt = S::from_f64(duration_secs);
+        let mut outputs = core::mem::take(&mut self.scratch.0);
+
+        outputs.resize(self.values.len(), Scalar::ZERO);
+
+        for _ in 0..steps {
+            self.step(dt, &mut outputs, waves);
+            self.elapsed.0 += duration_secs;
+        }
+
+        self.scratch.0 = outputs;
+    }
+
+    /// Advances a single timestep in which activity travels all the way from
+    /// the input column to the output column, whatever the Lobe's
+    /// [PropagationMode]; see [PropagationMode::Full].
+    pub fn tick_propagate_full(&mut self, duration_secs: f64) {
+        self.tick_waves(1, duration_secs, self.dims.0);
+    }
+
+    /// Ticks once for `duration_secs`, then passes the resulting output to
+    /// `observer`, for logging, plotting or early stopping.
+    pub fn tick_with<F: FnMut(&[S])>(&mut self, duration_secs: f64, mut observer: F) {
+        self.tick(duration_secs);
+        observer(self.get_output());
+    }
+
+    /// Lazily processes a stream of input frames: for each frame, applies it,
+    /// ticks once for `duration_secs`, and yields a copy of the output.
+    ///
+    /// Frames are only consumed as outputs are pulled, so the Lobe advances
+    /// exactly as far as the returned iterator is driven.
+    pub fn process_stream<'a, I>(
+        &'a mut self,
+        inputs: I,
+        duration_secs: f64,
+    ) -> impl Iterator<Item = Vec<S>> + 'a
+    where
+        I: Iterator<Item = Vec<S>> + 'a,
+    {
+        inputs.map(move |frame| {
+            self.apply_input(&frame);
+            self.tick(duration_secs);
+            self.get_output().to_vec()
+        })
+    }
+
+    /// Returns how far activity travels on each tick.
+    pub fn propagation_mode(&self) -> PropagationMode {
+        self.propagation_mode
+    }
+
+    /// Sets how far activity travels on each tick.
+    pub fn set_propagation_mode(&mut self, mode: PropagationMode) {
+        self.propagation_mode = mode;
+    }
+
+    /// Whether every column shares the weights, strengths and thresholds of
+    /// the first column.
+    pub fn tied(&self) -> bool {
+        self.tied
+    }
+
+    /// Sets whether every column shares the weights, strengths and
+    /// thresholds of the first column, which then acts as a template, like
+    /// the kernel of a convolution.
+    ///
+    /// Tying copies the template into every other column. While tied, every
+    /// column accessor views the template, so an edit through any column
+    /// changes them all alike, and the default learning rule pools every
+    /// column's updates into it. [Lobe::all_parameters_owned] and the
+    /// Lobe's hash only cover the template.
+    ///
+    /// The other columns are kept as copies of the template, so tying saves
+    /// no memory, and untying leaves each column with its own copy.
+    pub fn set_tied(&mut self, tied: bool) {
+        // Brings the copies up to date with edits made through the accessors
+        // before they are read as parameters of their own.
+        self.sync_tied();
+        self.tied = tied;
+        self.sync_tied();
+    }
+
+    /// Copies the template column's parameters into every other column,
+    /// if tied, after they were edited in bulk.
+    fn sync_tied(&mut self) {
+        if !self.tied {
+            return;
+        }
+
+        let breadth = self.dims.1;
+        let neighbors = self.connectivity.neighbors();
+
+        for (buffer, per_neuron) in [
+            (&mut self.weights, neighbors),
+            (&mut self.strengths, 1),
+            (&mut self.thresholds, 1),
+        ] {
+            let column = breadth * per_neuron;
+
+            for col in 1..self.dims.0 {
+                buffer.copy_within(..column, col * column);
+            }
+        }
+    }
+
+    /// Translates a column into the column its parameters are read from,
+    /// which is the template column while tied.
+    fn param_column(&self, which: usize) -> usize {
+        match self.tied && which < self.dims.0 {
+            true => 0,
+            false => which,
+        }
+    }
+
+    /// Translates a flat index into the values into the index of the
+    /// neuron's strength, threshold and chunk of weights, which is in the
+    /// template column while tied.
+    fn param_index(&self, index: usize) -> usize {
+        match self.tied && index < self.thresholds.len() {
+            true => index % self.dims.1,
+            false => index,
+        }
+    }
+
+    /// Iterates on a per-neuron parameter of every neuron outside the output
+    /// column, repeating the first `template` entries, which is the template
+    /// column while tied or the whole buffer otherwise.
+    fn per_neuron<T>(buffer: &[T], template: usize) -> impl Iterator<Item = &T> {
+        buffer[..template].iter().cycle().take(buffer.len())
+    }
+
+    /// Returns how many neurons' worth of parameters ticks read.
+    fn template_len(&self) -> usize {
+        match self.tied {
+            true => self.dims.1.min(self.thresholds.len()),
+            false => self.thresholds.len(),
+        }
+    }
+
+    /// Expands parameters laid out like [Lobe::all_parameters_owned] into
+    /// the layout of an untied Lobe, repeating the template for every column
+    /// if tied.
+    fn untie_parameters(&self, params: Vec<S>) -> Vec<S> {
+        if !self.tied {
+            return params;
+        }
+
+        let template = self.template_len();
+        let (thresholds, rest) = params.split_at(template);
+        let (weights, rest) = rest.split_at(template * self.connectivity.neighbors());
+        let (strengths, rest) = rest.split_at(template);
+
+        [thresholds, weights, strengths]
+            .into_iter()
+            .flat_map(|block| block.repeat(self.dims.0))
+            .chain(rest.iter().copied())
+            .collect()
+    }
+
+    /// References the rule this Lobe learns by, if one was set.
+    pub fn learning_rule(&self) -> Option<&dyn LearningRule<S>> {
+        self.learning_rule.0.as_deref()
+    }
+
+    /// Sets the rule this Lobe learns by when rewarded, or `None`, the
+    /// default, for a [HebbianRule].
+    pub fn set_learning_rule(&mut self, rule: Option<Box<dyn LearningRule<S>>>) {
+        self.learning_rule.0 = rule;
+    }
+
+    /// Returns the function applied to firing neurons' values before they
+    /// are weighted.
+    pub fn transfer(&self) -> Transfer<S> {
+        self.transfer
+    }
+
+    /// Sets the function applied to firing neurons' values before they are
+    /// weighted, shaping how much a very active neuron contributes.
+    pub fn set_transfer(&mut self, transfer: Transfer<S>) {
+        self.transfer = transfer;
+    }
+
+    /// Returns how many waves each spike takes to reach its targets.
+    pub fn delay_waves(&self) -> u32 {
+        self.delay.waves()
+    }
+
+    /// Sets how many waves each spike takes to reach its targets, modeling
+    /// conduction delays. The default of zero delivers spikes on the wave
+    /// they are fired.
+    ///
+    /// Under the default [PropagationMode::Stepwise], there is one wave per
+    /// tick, so a spike fired on tick `T` arrives on tick `T + delay`. Under
+    /// [PropagationMode::Full], a tick has one wave per column, so the delay
+    /// is counted in waves within and across ticks. Any spikes still in
+    /// flight are dropped.
+    pub fn set_delay_waves(&mut self, delay: u32) {
+        self.delay = DelayLine::new(delay, self.values.len());
+    }
+
+    /// References the rule neurons fire by at random, if any.
+    pub fn stochastic_firing(&self) -> Option<&StochasticFiring<S>> {
+        self.stochastic.as_ref()
+    }
+
+    /// Sets a rule for neurons to fire by at random, depending on how far
+    /// their value is over their threshold, or `None`, the default, for
+    /// neurons to always fire exactly at their threshold.
+    pub fn set_stochastic_firing(&mut self, firing: Option<StochasticFiring<S>>) {
+        self.stochastic = firing;
+    }
+
+    /// Returns how values that leave the scalar type's range are handled.
+    pub fn overflow_mode(&self) -> OverflowMode {
+        self.overflow_mode
+    }
+
+    /// Sets how values that leave the scalar type's range are handled while
+    /// spikes are propagated and accumulated, and while values decay.
+    pub fn set_overflow_mode(&mut self, mode: OverflowMode) {
+        self.overflow_mode = mode;
+    }
+
+    /// Returns how many waves a regular tick runs under the current
+    /// [PropagationMode].
+    fn waves_per_tick(&self) -> usize {
+        match self.propagation_mode {
+            PropagationMode::Stepwise => 1,
+            PropagationMode::Full => self.dims.0,
+        }
+    }
+
+    /// Creates a new Lobe from a list of parameters, loaded in the same order
+    /// they would be concatenated in [all_parameters_owned].
+    ///
+    /// Panics if the list has the wrong length; see [Lobe::try_from_parameters].
+    pub fn from_parameters(dims: (usize, usize), params: &[S]) -> Self {
+        Self::try_from_parameters(dims, params).expect("invalid Lobe parameters")
+    }
+
+    /// Creates a new Lobe from a list of parameters, loaded in the same order
+    /// they would be concatenated in [all_parameters_owned], checking that the
+    /// list has the right length for the given dimensions.
+    ///
+    /// The list holds the thresholds, weights and strengths of every neuron
+    /// outside the output column, then the shared falloff and the reset
+    /// value. If it has one extra parameter per value, those are loaded as
+    /// per-neuron falloff rates; if it has two, the second set is loaded as
+    /// per-neuron biases.
+    pub fn try_from_parameters(dims: (usize, usize), params: &[S]) -> Result<Self, ParamError> {
+        Self::try_from_parameters_with(dims, Connectivity::default(), params)
+    }
+
+    /// Like [Lobe::try_from_parameters], but for a Lobe connected in the
+    /// given pattern, which determines how many weights each neuron has.
+    pub fn try_from_parameters_with(
+        dims: (usize, usize),
+        connectivity: Connectivity,
+        params: &[S],
+    ) -> Result<Self, ParamError> {
+        let area = dims.0 * dims.1;
+        let weights = area * connectivity.neighbors();
+        let expected = area * 2 + weights + 2;
+        let neurons = area + dims.1;
+
+        let (falloff_per_neuron, bias) = match params.len() {
+            len if len == expected => (None, None),
+            len if len == expected + neurons => (Some(params[expected..].to_vec()), None),
+            len if len == expected + neurons * 2 => (
+                Some(params[expected..expected + neurons].to_vec()),
+                Some(params[expected + neurons..].to_vec()),
+            ),
+            got => return Err(ParamError::LengthMismatch { expected, got }),
+        };
+
+        let mut lobe = Self::with_connectivity(dims.1, dims.0, params[expected - 2], connectivity);
+
+        lobe.set_reset_value(params[expected - 1]);
+
+        lobe.thresholds = params[0..area].to_vec();
+        lobe.weights = params[area..area + weights].to_vec();
+        lobe.strengths = params[area + weights..area * 2 + weights].to_vec();
+        lobe.falloff_per_neuron = falloff_per_neuron;
+        lobe.bias = bias;
+
+        Ok(lobe)
+    }
+
+    /// Overwrites a parameter block in place, if the lengths match.
+    fn set_block_from(block: &mut [S], params: &[S]) -> Result<(), ParamError> {
+        if params.len() != block.len() {
+            return Err(ParamError::LengthMismatch {
+                expected: block.len(),
+                got: params.len(),
+            });
+        }
+
+        block.copy_from_slice(params);
+
+        Ok(())
+    }
+
+    /// Overwrites only the forward weights, leaving every other parameter
+    /// untouched.
+    ///
+    /// `params` is laid out like the weights block of
+    /// [Lobe::all_parameters_owned], with [Connectivity::neighbors] weights
+    /// per neuron, so while tied it only holds the template's.
+    pub fn set_weights_from(&mut self, params: &[S]) -> Result<(), ParamError> {
+        let template = self.template_len() * self.connectivity.neighbors();
+
+        Self::set_block_from(&mut self.weights[..template], params)?;
+        self.sync_tied();
+
+        Ok(())
+    }
+
+    /// Overwrites only the thresholds, one per neuron outside the output
+    /// column, or per template neuron while tied.
+    pub fn set_thresholds_from(&mut self, params: &[S]) -> Result<(), ParamError> {
+        let template = self.template_len();
+
+        Self::set_block_from(&mut self.thresholds[..template], params)?;
+        self.sync_tied();
+
+        Ok(())
+    }
+
+    /// Overwrites only the strengths, one per neuron outside the output
+    /// column, or per template neuron while tied.
+    pub fn set_strengths_from(&mut self, params: &[S]) -> Result<(), ParamError> {
+        let template = self.template_len();
+
+        Self::set_block_from(&mut self.strengths[..template], params)?;
+        self.sync_tied();
+
+        Ok(())
+    }
+}
+
+impl<S: Scalar> NeuralObject<S> for Lobe<S> {
+    fn input_size(&self) -> usize {
+        self.dims.1
+    }
+
+    fn apply_input(&mut self, inputs: &[S]) {
+        let breadth = self.dims.1;
+
+        for (row, (into, from)) in izip!(&mut self.values[..breadth], inputs).enumerate() {
+            if self.refractory.get(row).is_none_or(|c| *c == 0) {
+                *into += Self::normalize_input(&self.input_offset, &self.input_scale, row, *from);
+            }
+        }
+    }
+
+    /// Advances a single timestep.
+    ///
+    /// Panics if `duration_secs` is negative or NaN, since the values would
+    /// grow instead of decaying. A duration of zero leaves the Lobe
+    /// untouched, not even counting a tick.
+    fn tick(&mut self, duration_secs: f64) {
+        self.tick_n(1, duration_secs);
+    }
+
+    /// Advances several timesteps, reusing the Lobe's scratch buffer for all
+    /// of them instead of allocating.
+    fn tick_n(&mut self, steps: usize, duration_secs: f64) {
+        self.tick_waves(steps, duration_secs, self.waves_per_tick());
+    }
+
+    fn get_output(&self) -> &[S] {
+        self.value_column_ref(self.dims.0)
+    }
+
+    fn output_size(&self) -> usize {
+        self.dims.1
+    }
+
+    /// Applies the Lobe's learning rule, which is a reward-modulated
+    /// [HebbianRule] unless another was set with [Lobe::set_learning_rule].
+    fn reward(&mut self, reward: S) {
+        match self.learning_rule.0.take() {
+            Some(rule) => {
+                rule.apply(self, reward);
+                self.learning_rule.0 = Some(rule);
+            }
+            None => HebbianRule.apply(self, reward),
+        }
+
+        self.sync_tied();
+    }
+
+    fn reset(&mut self) {
+        self.values.fill(S::ZERO);
+        self.fired.fill(false);
+        self.refractory.fill(0);
+        self.eligibility.fill(S::ZERO);
+        self.firing_rates.fill(S::ZERO);
+        self.delay.clear();
+        self.reset_elapsed_secs();
+    }
+
+    fn elapsed_secs(&self) -> f64 {
+        self.elapsed.0
+    }
+
+    fn clone_box(&self) -> Box<dyn NeuralObject<S>> {
+        Box::new(self.clone())
+    }
+}
+
+/// Reads the value of the neuron at a `(col, row)` position, like
+/// [Lobe::value_at], but panicking if it is out of range.
+impl<S: Scalar> Index<(usize, usize)> for Lobe<S> {
+    type Output = S;
+
+    fn index(&self, position: (usize, usize)) -> &S {
+        &self.values[self.value_index(position)]
+    }
+}
+
+/// Mutably references the value of the neuron at a `(col, row)` position,
+/// panicking if it is out of range.
+impl<S: Scalar> IndexMut<(usize, usize)> for Lobe<S> {
+    fn index_mut(&mut self, position: (usize, usize)) -> &mut S {
+        let index = self.value_index(position);
+        &mut self.values[index]
+    }
+}
+
+/// Summary statistics of a parameter vector, for [Lobe]'s `Debug` output.
+struct Summary {
+    min: f64,
+    max: f64,
+    mean: f64,
+}
+
+impl Summary {
+    fn of<S: Scalar>(values: &[S]) -> Option<Self> {
+        let first = values.first()?.to_f64();
+        let mut summary = Summary {
+            min: first,
+            max: first,
+            mean: 0.0,
+        };
+
+        for value in values.iter().map(|value| value.to_f64()) {
+            summary.min = summary.min.min(value);
+            summary.max = summary.max.max(value);
+            summary.mean += value;
+        }
+
+        summary.mean /= values.len() as f64;
+
+        Some(summary)
+    }
+}
+
+impl fmt::Debug for Summary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Summary")
+            .field("min", &self.min)
+            .field("max", &self.max)
+            .field("mean", &self.mean)
+            .finish()
+    }
+}
+
+impl<S: Scalar> fmt::Debug for Lobe<S> {
+    /// Prints the dimensions and falloff, and summarizes every parameter
+    /// vector instead of dumping it in full.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Lobe")
+            .field("dims", &self.dims)
+            .field("connectivity", &self.connectivity)
+            .field("falloff", &self.falloff.to_f64())
+            .field("weights", &Summary::of(&self.weights))
+            .field("strengths", &Summary::of(&self.strengths))
+            .field("thresholds", &Summary::of(&self.thresholds))
+            .field("values", &Summary::of(&self.values))
+            .finish_non_exhaustive()
+    }
+}
+
+impl<S: Scalar> Default for Lobe<S> {
+    /// Creates an empty Lobe, with no neurons and no falloff.
+    fn default() -> Self {
+        Self::new(0, 0, S::ZERO)
+    }
+}
+
+/// An error found while building a Lobe.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum BuildError {
+    /// A parameter list does not have the length the dimensions call for.
+    LengthMismatch {
+        parameter: &'static str,
+        expected: usize,
+        got: usize,
+    },
+}
+
+impl fmt::Display for BuildError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BuildError::LengthMismatch {
+                parameter,
+                expected,
+                got,
+            } => write!(f, "expected {} {}, but got {}", expected, parameter, got),
+        }
+    }
+}
+
+impl core::error::Error for BuildError {}
+
+/// Builds a [Lobe] piece by piece, validating every parameter list against
+/// the dimensions. Parameters that are not given are zeroed.
+#[derive(Clone)]
+pub struct LobeBuilder<S: Scalar = Amount> {
+    dims: (usize, usize),
+    falloff: S,
+    connectivity: Connectivity,
+    weights: Option<Vec<S>>,
+    strengths: Option<Vec<S>>,
+    thresholds: Option<Vec<S>>,
+}
+
+impl<S: Scalar> Default for LobeBuilder<S> {
+    fn default() -> Self {
+        Self {
+            dims: (0, 0),
+            falloff: S::ZERO,
+            connectivity: Connectivity::default(),
+            weights: None,
+            strengths: None,
+            thresholds: None,
+        }
+    }
+}
+
+impl<S: Scalar> LobeBuilder<S> {
+    /// Creates a new builder for an empty Lobe.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the dimensions, in the same order as [Lobe::get_dims].
+    pub fn dims(mut self, dims: (usize, usize)) -> Self {
+        self.dims = dims;
+        self
+    }
+
+    /// Sets the falloff.
+    pub fn falloff(mut self, falloff: S) -> Self {
+        self.falloff = falloff;
+        self
+    }
+
+    /// Sets the pattern the neurons are connected in.
+    pub fn connectivity(mut self, connectivity: Connectivity) -> Self {
+        self.connectivity = connectivity;
+        self
+    }
+
+    /// Sets the forward weights, one per neighbor of each neuron.
+    pub fn weights(mut self, weights: &[S]) -> Self {
+        self.weights = Some(weights.to_vec());
+        self
+    }
+
+    /// Sets the firing strengths, one per neuron.
+    pub fn strengths(mut self, strengths: &[S]) -> Self {
+        self.strengths = Some(strengths.to_vec());
+        self
+    }
+
+    /// Sets the firing thresholds, one per neuron.
+    pub fn thresholds(mut self, thresholds: &[S]) -> Self {
+        self.thresholds = Some(thresholds.to_vec());
+        self
+    }
+
+    /// Builds the Lobe, checking that every given parameter list fits the
+    /// dimensions.
+    pub fn build(self) -> Result<Lobe<S>, BuildError> {
+        let (width, breadth) = self.dims;
+        let mut lobe = Lobe::with_connectivity(breadth, width, self.falloff, self.connectivity);
+
+        for (parameter, given, into) in [
+            ("weights", self.weights, &mut lobe.weights),
+            ("strengths", self.strengths, &mut lobe.strengths),
+            ("thresholds", self.thresholds, &mut lobe.thresholds),
+        ] {
+            if let Some(given) = given {
+                if given.len() != into.len() {
+                    return Err(BuildError::LengthMismatch {
+                        parameter,
+                        expected: into.len(),
+                        got: given.len(),
+                    });
+                }
+
+                *into = given;
+            }
+        }
+
+        Ok(lobe)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::SplitMix64;
+    use alloc::format;
+
+    #[test]
+    fn randomized_lobes_are_reproducible() {
+        let lobe =
+            |seed| Lobe::randomized(3, 2, Amount::ZERO, &mut SplitMix64::seed_from_u64(seed));
+
+        assert!(lobe(3) == lobe(3));
+        assert!(lobe(3) != lobe(4));
+        assert!(lobe(3)
+            .thresholds
+            .iter()
+            .all(|t| *t >= Amount::from_num(0.25)));
+    }
+
+    #[test]
+    fn falloff_setter_clamps_negative_rates() {
+        let mut lobe = Lobe::new(1, 1, Amount::ZERO);
+
+        lobe.set_falloff(Amount::from_num(0.5));
+        assert_eq!(lobe.falloff(), Amount::from_num(0.5));
+
+        lobe.set_falloff(-Amount::ONE);
+        assert_eq!(lobe.falloff(), Amount::ZERO);
+    }
+
+    #[test]
+    fn falloff_leaks_values_towards_zero() {
+        let mut lobe = Lobe::new(1, 1, Amount::ZERO);
+
+        lobe.thresholds.fill(Amount::ONE);
+        lobe.set_falloff(Amount::from_num(0.5));
+        lobe.apply_input(&[Amount::from_num(0.5)]);
+        lobe.tick(1.0);
+
+        assert_eq!(lobe.value_column_ref(0), [Amount::from_num(0.25)]);
+    }
+
+    #[test]
+    fn reset_clears_transient_state_but_keeps_parameters() {
+        let mut lobe = Lobe::randomized(2, 2, Amount::ZERO, &mut SplitMix64::seed_from_u64(1))
+            .with_refractory_ticks(3);
+        let fresh = lobe.clone();
+
+        lobe.apply_input(&[Amount::ONE, Amount::ONE]);
+        lobe.tick(1.0);
+        lobe.reset();
+
+        assert!(lobe.values.iter().all(|value| *value == Amount::ZERO));
+        assert!(lobe.fired.iter().all(|fired| !fired));
+        assert!(lobe.refractory.iter().all(|countdown| *countdown == 0));
+        assert!(lobe.eligibility.iter().all(|trace| *trace == Amount::ZERO));
+        assert_eq!(lobe.elapsed_secs(), 0.0);
+        assert_eq!(lobe.all_parameters_owned(), fresh.all_parameters_owned());
+    }
+
+    #[test]
+    fn parameters_of_the_wrong_length_are_rejected() {
+        let lobe =
+            Lobe::<Amount>::randomized(2, 3, Amount::ZERO, &mut SplitMix64::seed_from_u64(2));
+        let mut params = lobe.all_parameters_owned();
+
+        assert!(Lobe::try_from_parameters((3, 2), &params).ok() == Some(lobe));
+
+        params.pop();
+
+        assert_eq!(
+            Lobe::try_from_parameters((3, 2), &params).err(),
+            Some(ParamError::LengthMismatch {
+                expected: params.len() + 1,
+                got: params.len(),
+            })
+        );
+    }
+
+    #[test]
+    fn non_square_lobes_load_with_a_full_output_column() {
+        let params = Lobe::<Amount>::new(3, 1, Amount::ZERO).all_parameters_owned();
+        let lobe = Lobe::from_parameters((1, 3), &params);
+
+        assert_eq!(lobe.get_output().len(), 3);
+    }
+
+    /// A Lobe two neurons long where each neuron relays a value of one to
+    /// the next.
+    fn relay_chain() -> Lobe {
+        let mut lobe = Lobe::new(1, 2, Amount::ZERO);
+
+        lobe.weights.fill(Amount::ONE);
+        lobe.strengths.fill(Amount::ONE);
+        lobe.thresholds.fill(Amount::from_num(0.5));
+        lobe
+    }
+
+    #[test]
+    fn refractory_neurons_stay_at_zero() {
+        let mut lobe = relay_chain().with_refractory_ticks(2);
+        let mut values = vec![];
+
+        for _ in 0..4 {
+            lobe.apply_input(&[Amount::ONE]);
+            values.push(lobe.values[0]);
+            lobe.tick(1.0);
+        }
+
+        assert_eq!(
+            values,
+            [Amount::ONE, Amount::ZERO, Amount::ZERO, Amount::ONE]
+        );
+    }
+
+    #[test]
+    fn one_refractory_tick_ignores_the_next_tick_entirely() {
+        let mut lobe = relay_chain().with_refractory_ticks(1);
+        let mut second = vec![];
+
+        lobe.values[1] = Amount::ONE;
+
+        for _ in 0..3 {
+            lobe.apply_input(&[Amount::ONE]);
+            lobe.tick(1.0);
+            second.push(lobe.values[1]);
+        }
+
+        // The second neuron fires on the first tick, so what the first one
+        // sends it on the second tick is dropped.
+        assert_eq!(second, [Amount::ZERO, Amount::ZERO, Amount::ONE]);
+    }
+
+    #[test]
+    fn no_refractory_period_keeps_integrating_after_firing() {
+        let mut lobe = relay_chain();
+
+        lobe.values[1] = Amount::ONE;
+        lobe.apply_input(&[Amount::ONE]);
+        lobe.tick(1.0);
+
+        assert_eq!(lobe.values[1], Amount::ONE);
+    }
+
+    /// A 1x1 Lobe whose input fires once into the output with the given
+    /// weight, the output starting at one half.
+    fn single_spike(weight: Amount) -> Lobe {
+        let mut lobe = Lobe::new(1, 1, Amount::ZERO);
+
+        lobe.weights.fill(weight);
+        lobe.strengths.fill(Amount::ONE);
+        lobe.thresholds.fill(Amount::from_num(0.5));
+        lobe.apply_input(&[Amount::ONE]);
+        lobe.values[1] = Amount::from_num(0.5);
+        lobe
+    }
+
+    #[test]
+    fn negative_weights_inhibit_down_to_the_floor() {
+        let mut excited = single_spike(Amount::from_num(0.25));
+        let mut inhibited = single_spike(Amount::from_num(-0.25));
+        let mut clamped = single_spike(-Amount::ONE);
+        let mut floored = single_spike(-Amount::ONE);
+
+        floored.set_min_value(Amount::from_num(-0.25));
+
+        for lobe in [&mut excited, &mut inhibited, &mut clamped, &mut floored] {
+            lobe.tick(1.0);
+        }
+
+        assert_eq!(excited.get_output(), [Amount::from_num(0.75)]);
+        assert_eq!(inhibited.get_output(), [Amount::from_num(0.25)]);
+        assert_eq!(clamped.get_output(), [Amount::ZERO]);
+        assert_eq!(floored.get_output(), [Amount::from_num(-0.25)]);
+    }
+
+    #[test]
+    fn spike_counts_count_each_neurons_firing() {
+        let mut lobe = Lobe::<Amount>::new(2, 1, Amount::ZERO);
+
+        lobe.thresholds.fill(Amount::from_num(0.5));
+
+        for _ in 0..3 {
+            lobe.apply_input(&[Amount::ONE, Amount::ZERO]);
+            lobe.tick(1.0);
+        }
+
+        assert_eq!(lobe.spike_counts(), [3, 0]);
+
+        lobe.reset_spike_counts();
+
+        assert_eq!(lobe.spike_counts(), [0, 0]);
+    }
+
+    #[test]
+    fn neuron_accessors_check_bounds() {
+        let mut lobe = Lobe::<Amount>::new(2, 3, Amount::ZERO);
+
+        *lobe.value_at_mut(3, 1).unwrap() = Amount::ONE;
+        *lobe.threshold_at_mut(2, 1).unwrap() = Amount::ONE;
+
+        assert_eq!(lobe.get_output(), [Amount::ZERO, Amount::ONE]);
+        assert_eq!(lobe.threshold_column_ref(2), [Amount::ZERO, Amount::ONE]);
+        assert_eq!(lobe.value_at(4, 0), None);
+        assert_eq!(lobe.value_at(0, 2), None);
+        assert_eq!(lobe.strength_at(3, 0), None);
+        assert!(lobe.threshold_at_mut(3, 0).is_none());
+        assert!(lobe.strength_at_mut(0, 2).is_none());
+    }
+
+    #[test]
+    fn output_size_matches_the_output() {
+        for (breadth, width) in [(3, 2), (1, 0), (0, 4)] {
+            let lobe = Lobe::<Amount>::new(breadth, width, Amount::ZERO);
+
+            assert_eq!(lobe.output_size(), breadth);
+            assert_eq!(lobe.output_size(), lobe.get_output().len());
+        }
+    }
+
+    #[test]
+    fn rows_stride_across_columns() {
+        let mut lobe = Lobe::<Amount>::new(2, 2, Amount::ZERO);
+
+        for (index, value) in lobe.values.iter_mut().enumerate() {
+            *value = Amount::from_num(index);
+        }
+
+        lobe.thresholds.fill(Amount::ONE);
+
+        assert!(lobe
+            .value_row(1)
+            .copied()
+            .eq([1, 3, 5].map(Amount::from_num)));
+        assert_eq!(lobe.threshold_row(0).count(), 2);
+        assert_eq!(lobe.strength_row(1).count(), 2);
+        assert_eq!(lobe.value_row(2).count(), 0);
+    }
+
+    #[test]
+    fn default_lobes_are_empty() {
+        let lobe = Lobe::<Amount>::default();
+
+        assert_eq!(lobe.get_dims(), (0, 0));
+        assert!(lobe.get_output().is_empty());
+    }
+
+    #[test]
+    fn builder_checks_parameter_lengths() {
+        let thresholds = [Amount::ONE; 4];
+        let lobe = Lobe::builder()
+            .dims((2, 2))
+            .falloff(Amount::from_num(0.5))
+            .thresholds(&thresholds)
+            .build()
+            .unwrap();
+
+        assert_eq!(lobe.falloff(), Amount::from_num(0.5));
+        assert_eq!(lobe.thresholds, thresholds);
+        assert_eq!(lobe.weights, [Amount::ZERO; 12]);
+        assert_eq!(
+            Lobe::builder()
+                .dims((2, 2))
+                .strengths(&[Amount::ONE; 3])
+                .build()
+                .err(),
+            Some(BuildError::LengthMismatch {
+                parameter: "strengths",
+                expected: 4,
+                got: 3,
+            })
+        );
+    }
+
+    #[test]
+    fn each_neuron_leaks_at_its_own_falloff() {
+        let mut lobe = Lobe::<Amount>::new(2, 1, Amount::ZERO);
+        let half = Amount::from_num(0.5);
+
+        lobe.thresholds.fill(Amount::ONE);
+        lobe.set_falloff_per_neuron(Some(vec![half, Amount::ZERO, -half, half]))
+            .unwrap();
+        lobe.apply_input(&[half, half]);
+        lobe.tick(1.0);
+
+        assert_eq!(lobe.falloff_per_neuron().unwrap()[2], Amount::ZERO);
+        assert_eq!(lobe.value_column_ref(0), [Amount::from_num(0.25), half]);
+        assert_eq!(
+            lobe.set_falloff_per_neuron(Some(vec![half; 3])),
+            Err(ParamError::LengthMismatch {
+                expected: 4,
+                got: 3
+            })
+        );
+    }
+
+    #[test]
+    fn input_at_a_row_leaves_the_others_alone() {
+        let mut lobe = Lobe::<Amount>::new(3, 1, Amount::ZERO);
+
+        lobe.apply_input_at(1, Amount::ONE);
+        lobe.apply_input_at(1, Amount::ONE);
+
+        assert_eq!(
+            lobe.value_column_ref(0),
+            [Amount::ZERO, Amount::from_num(2), Amount::ZERO]
+        );
+        assert_eq!(
+            lobe.try_apply_input_at(3, Amount::ONE),
+            Err(SpikerError::IndexOutOfRange { index: 3, len: 3 })
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "out of range")]
+    fn input_at_a_missing_row_panics() {
+        Lobe::<Amount>::new(3, 1, Amount::ZERO).apply_input_at(3, Amount::ONE);
+    }
+
+    #[test]
+    fn output_spikes_are_the_positive_outputs() {
+        let mut lobe = Lobe::<Amount>::new(3, 1, Amount::ZERO);
+
+        lobe.apply_input(&[Amount::ONE; 3]);
+        *lobe.value_at_mut(1, 0).unwrap() = Amount::from_num(0.5);
+        *lobe.value_at_mut(1, 2).unwrap() = -Amount::ONE;
+
+        assert_eq!(lobe.output_spikes(), [true, false, false]);
+    }
+
+    #[test]
+    fn tick_n_matches_ticking_one_at_a_time() {
+        let mut once = Lobe::<Amount>::randomized(
+            3,
+            3,
+            Amount::from_num(0.25),
+            &mut SplitMix64::seed_from_u64(5),
+        );
+
+        once.apply_input(&[Amount::ONE; 3]);
+
+        let mut many = once.clone();
+
+        for _ in 0..4 {
+            once.tick(0.5);
+        }
+
+        many.tick_n(4, 0.5);
+
+        assert!(many == once);
+    }
+
+    #[test]
+    fn ticks_reuse_the_scratch_buffer() {
+        let mut lobe = Lobe::<Amount>::new(3, 2, Amount::ZERO);
+
+        lobe.tick(1.0);
+
+        let buffer = lobe.scratch.0.as_ptr();
+
+        lobe.tick(1.0);
+        lobe.tick_n(3, 1.0);
+
+        assert_eq!(lobe.scratch.0.len(), lobe.values.len());
+        assert_eq!(lobe.scratch.0.as_ptr(), buffer);
+        assert!(lobe.clone().scratch.0.is_empty());
+    }
+
+    #[test]
+    fn debug_output_summarizes_instead_of_dumping() {
+        let mut small = Lobe::<Amount>::new(2, 3, Amount::ZERO);
+        let large = Lobe::<Amount>::new(20, 30, Amount::ZERO);
+
+        small.thresholds.fill(Amount::ONE);
+
+        let debug = format!("{:?}", small);
+
+        assert!(debug.starts_with("Lobe { dims: (3, 2)"));
+        assert!(debug.contains("thresholds: Some(Summary { min: 1.0, max: 1.0, mean: 1.0 })"));
+        assert!(format!("{:?}", large).len() < 400);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn normalized_incoming_weights_have_the_target_norm() {
+        let mut lobe = Lobe::<Amount>::new(2, 1, Amount::ZERO);
+
+        lobe.weights.fill(Amount::ONE);
+        lobe.normalize_weights(Amount::ONE);
+
+        // The weights from rows 0 and 1 into row 0 of the output.
+        let incoming = [lobe.weights[1], lobe.weights[3]];
+        let norm: f64 = incoming.iter().map(|w| w.to_f64().powi(2)).sum();
+
+        assert!((norm - 1.0).abs() < 0.01);
+        assert_eq!(incoming[0], incoming[1]);
+    }
+
+    #[test]
+    fn clamped_thresholds_stay_in_range() {
+        let mut lobe = Lobe::<Amount>::new(3, 1, Amount::ZERO);
+
+        lobe.thresholds.copy_from_slice(&[
+            -Amount::ONE,
+            Amount::from_num(0.5),
+            Amount::from_num(3),
+        ]);
+        lobe.clamp_thresholds(Amount::ZERO, Amount::ONE);
+
+        assert_eq!(
+            lobe.thresholds,
+            [Amount::ZERO, Amount::from_num(0.5), Amount::ONE]
+        );
+    }
+
+    #[test]
+    fn pruning_silences_neurons_that_rarely_fired() {
+        let mut lobe = Lobe::<Amount>::new(2, 1, Amount::ZERO);
+
+        lobe.weights.fill(Amount::ONE);
+        lobe.strengths.fill(Amount::ONE);
+        lobe.thresholds.fill(Amount::from_num(0.5));
+
+        for _ in 0..2 {
+            lobe.apply_input(&[Amount::ONE, Amount::ZERO]);
+            lobe.tick(1.0);
+        }
+
+        assert_eq!(lobe.prune_inactive(2), 1);
+        assert_eq!(lobe.strength_column_ref(0), [Amount::ONE, Amount::ZERO]);
+        assert_eq!(lobe.weights[2], Amount::ONE);
+        assert_eq!(lobe.weights[4], Amount::ZERO);
+        assert_eq!(lobe.spike_counts(), [2, 0]);
+    }
+
+    #[test]
+    fn partial_loads_only_touch_their_block() {
+        let mut lobe = Lobe::<Amount>::new(2, 1, Amount::ZERO);
+
+        lobe.thresholds.fill(Amount::ONE);
+        lobe.set_weights_from(&[Amount::from_num(0.5); 6]).unwrap();
+        lobe.set_strengths_from(&[Amount::from_num(0.25); 2])
+            .unwrap();
+
+        assert_eq!(lobe.weights, [Amount::from_num(0.5); 6]);
+        assert_eq!(lobe.strengths, [Amount::from_num(0.25); 2]);
+        assert_eq!(lobe.thresholds, [Amount::ONE; 2]);
+        assert_eq!(
+            lobe.set_thresholds_from(&[Amount::ZERO; 3]),
+            Err(ParamError::LengthMismatch {
+                expected: 2,
+                got: 3
+            })
+        );
+        assert_eq!(lobe.thresholds, [Amount::ONE; 2]);
+    }
+
+    #[test]
+    fn float_readouts_follow_the_fixed_point_values() {
+        let mut lobe = Lobe::<Amount>::new(2, 1, Amount::from_num(0.5));
+
+        *lobe.value_at_mut(1, 1).unwrap() = Amount::from_num(-1.25);
+        lobe.thresholds.fill(Amount::from_num(0.75));
+
+        let params = lobe.parameters_as_f64();
+
+        assert_eq!(lobe.output_as_f64(), [0.0, -1.25]);
+        assert_eq!(params.len(), lobe.all_parameters_owned().len());
+        assert_eq!(params[..2], [0.75, 0.75]);
+        assert_eq!(params[params.len() - 2], 0.5);
+    }
+
+    #[test]
+    fn recurrent_gain_feeds_the_output_back_into_the_input() {
+        let mut lobe = Lobe::<Amount>::new(2, 1, Amount::ZERO);
+
+        lobe.thresholds.fill(Amount::from_num(2));
+        *lobe.value_at_mut(1, 0).unwrap() = Amount::ONE;
+
+        let mut open = lobe.clone();
+
+        open.set_recurrent_gain(Amount::from_num(0.5));
+        lobe.tick(1.0);
+        open.tick(1.0);
+
+        assert_eq!(lobe.value_column_ref(0), [Amount::ZERO; 2]);
+        assert_eq!(
+            open.value_column_ref(0),
+            [Amount::from_num(0.5), Amount::ZERO]
+        );
+    }
+
+    #[test]
+    fn adapting_thresholds_pulls_rates_towards_the_target() {
+        let half = Amount::from_num(0.5);
+        let mut lobe = Lobe::<Amount>::new(2, 1, Amount::ZERO);
+
+        lobe.thresholds.fill(half);
+        lobe.adapt_thresholds(half, Amount::ONE);
+
+        assert_eq!(lobe.thresholds, [half; 2]);
+
+        for _ in 0..4 {
+            lobe.apply_input(&[Amount::ONE, Amount::ZERO]);
+            lobe.tick(1.0);
+        }
+
+        lobe.adapt_thresholds(half, Amount::ONE);
+
+        assert_eq!(lobe.thresholds, [Amount::ONE, Amount::ZERO]);
+        assert_eq!(lobe.counted_ticks(), 0);
+    }
+
+    #[test]
+    fn extra_parameters_load_as_falloffs_then_biases() {
+        let lobe = Lobe::<Amount>::new(2, 1, Amount::ZERO);
+        let mut params = lobe.all_parameters_owned();
+
+        params.extend([Amount::from_num(0.5); 4]);
+        let with_falloffs = Lobe::from_parameters((1, 2), &params);
+
+        params.extend([Amount::ONE; 4]);
+        let with_bias = Lobe::from_parameters((1, 2), &params);
+
+        assert_eq!(
+            with_falloffs.falloff_per_neuron(),
+            Some(&[Amount::from_num(0.5); 4][..])
+        );
+        assert_eq!(with_falloffs.bias(), None);
+        assert_eq!(with_bias.bias(), Some(&[Amount::ONE; 4][..]));
+    }
+
+    #[test]
+    fn bias_drives_neurons_to_fire_on_their_own() {
+        let mut lobe = Lobe::<Amount>::new(2, 1, Amount::ZERO);
+        let half = Amount::from_num(0.5);
+
+        lobe.thresholds.fill(Amount::ONE);
+        lobe.set_bias(Some(vec![half, Amount::ZERO, Amount::ZERO, Amount::ZERO]))
+            .unwrap();
+        lobe.tick(1.0);
+
+        assert_eq!(lobe.value_at(0, 0), Some(half));
+
+        lobe.tick(1.0);
+
+        assert_eq!(lobe.spike_counts(), [1, 0]);
+        assert_eq!(
+            lobe.set_bias(Some(vec![half; 2])),
+            Err(ParamError::LengthMismatch {
+                expected: 4,
+                got: 2
+            })
+        );
+    }
+
+    #[test]
+    fn approx_eq_tolerates_small_parameter_differences() {
+        let lobe = Lobe::<Amount>::new(2, 2, Amount::ZERO);
+        let mut nudged = lobe.clone();
+        let tol = Amount::from_num(0.125);
+
+        nudged.threshold_column_mut(1)[0] = tol;
+        nudged.value_column_mut(0)[0] = Amount::ONE;
+
+        assert!(lobe.approx_eq(&nudged, tol));
+        assert!(!lobe.approx_eq(&nudged, tol / 2));
+        assert!(!lobe.approx_eq(&Lobe::new(2, 3, Amount::ZERO), tol));
+    }
+
+    #[test]
+    fn sparse_input_matches_dense_input() {
+        let mut sparse = Lobe::<Amount>::new(4, 1, Amount::ZERO);
+        let mut dense = sparse.clone();
+
+        sparse.apply_sparse_input(&[
+            (3, Amount::ONE),
+            (1, Amount::from_num(0.5)),
+            (0, Amount::ZERO),
+        ]);
+        dense.apply_input(&[
+            Amount::ZERO,
+            Amount::from_num(0.5),
+            Amount::ZERO,
+            Amount::ONE,
+        ]);
+
+        assert_eq!(sparse, dense);
+        assert_eq!(
+            sparse.try_apply_sparse_input(&[(0, Amount::ONE), (4, Amount::ONE)]),
+            Err(SpikerError::IndexOutOfRange { index: 4, len: 4 })
+        );
+        assert_eq!(sparse, dense);
+    }
+
+    #[test]
+    fn eligibility_traces_fade_so_late_rewards_still_count() {
+        let mut lobe = Lobe::<Amount>::new(1, 1, Amount::ZERO);
+
+        lobe.weights.fill(Amount::ONE);
+        lobe.strengths.fill(Amount::ONE);
+        lobe.thresholds.fill(Amount::from_num(0.5));
+        lobe.set_learning_rate(Amount::ONE);
+        lobe.set_trace_decay(Amount::from_num(0.5));
+        lobe.apply_input(&[Amount::ONE]);
+        lobe.tick(1.0);
+
+        assert_eq!(lobe.eligibility()[1], Amount::ONE);
+
+        lobe.tick(1.0);
+
+        assert_eq!(lobe.eligibility()[1], Amount::from_num(0.5));
+
+        lobe.reward(Amount::ONE);
+
+        assert_eq!(lobe.weights[1], Amount::from_num(1.5));
+    }
+
+    #[test]
+    fn activity_metrics_sum_values_and_count_active_neurons() {
+        let mut lobe = Lobe::<Amount>::new(2, 1, Amount::ZERO);
+
+        lobe.thresholds.fill(Amount::ONE);
+        lobe.apply_input(&[Amount::ONE, Amount::from_num(0.5)]);
+        *lobe.value_at_mut(1, 0).unwrap() = Amount::from_num(0.25);
+        *lobe.value_at_mut(1, 1).unwrap() = -Amount::ONE;
+
+        assert_eq!(lobe.total_activation(), Amount::from_num(0.75));
+        assert_eq!(lobe.active_neuron_count(), 2);
+        assert_eq!(lobe.fire_raster(), [true, false, true, false]);
+    }
+
+    /// A Lobe two columns wide, so a spike crosses both before the output.
+    fn chain() -> Lobe<Amount> {
+        let mut lobe = Lobe::new(1, 2, Amount::ZERO);
+
+        lobe.thresholds.fill(Amount::from_num(0.5));
+        lobe
+    }
+
+    #[test]
+    fn firing_is_decided_once_before_anything_propagates() {
+        let mut lobe = chain();
+
+        lobe.weights.fill(Amount::ONE);
+        lobe.strengths.fill(Amount::ONE);
+        lobe.apply_input(&[Amount::ONE]);
+        lobe.tick(1.0);
+
+        // The hidden neuron crossed its threshold during the tick, but only
+        // fires on the next one, and the input neuron was reset for firing.
+        assert_eq!(lobe.spike_counts(), [1, 0]);
+        assert_eq!(lobe.value_column_ref(0), [Amount::ZERO]);
+        assert_eq!(lobe.value_column_ref(1), [Amount::ONE]);
+        assert_eq!(lobe.get_output(), [Amount::ZERO]);
+
+        lobe.tick(1.0);
+
+        assert_eq!(lobe.spike_counts(), [1, 1]);
+        assert_eq!(lobe.value_column_ref(1), [Amount::ZERO]);
+        assert_eq!(lobe.get_output(), [Amount::ONE]);
+    }
+
+    /// Only built without `std`, where everything below must come from
+    /// `core` and `alloc`.
+    #[cfg(not(feature = "std"))]
+    #[test]
+    fn lobes_work_without_the_standard_library() {
+        let mut rng = SplitMix64::seed_from_u64(6);
+        let mut lobe = Lobe::<Amount>::randomized(3, 2, Amount::from_num(0.25), &mut rng);
+
+        lobe.apply_input(&[Amount::ONE; 3]);
+        lobe.tick_adaptive(1.0, 0.3);
+        lobe.reward(Amount::ONE);
+        lobe.sparsify(0.5);
+
+        assert_eq!(lobe.counted_ticks(), 4);
+        assert!(lobe.sparsity() >= 0.5);
+        assert!(lobe.quantize_weights(2, (Amount::ZERO, Amount::ONE)) <= Amount::from_num(0.5));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn weight_schemes_scale_the_variance_by_fan_in() {
+        let variance = |he: bool| {
+            let mut lobe = Lobe::<f64>::new(64, 64, 0.0);
+            let mut rng = SplitMix64::seed_from_u64(10);
+
+            match he {
+                false => lobe.init_weights_xavier(&mut rng),
+                true => lobe.init_weights_he(&mut rng),
+            }
+
+            let count = lobe.weights.len() as f64;
+            lobe.weights.iter().map(|w| w * w).sum::<f64>() / count
+        };
+
+        assert!((variance(false) - 1.0 / 3.0).abs() < 0.02);
+        assert!((variance(true) - 2.0 / 3.0).abs() < 0.04);
+    }
+
+    #[test]
+    fn lateral_inhibition_lets_only_the_strongest_reach_threshold() {
+        let threshold = Amount::from_num(0.75);
+        let mut inhibited = Lobe::<Amount>::new(2, 1, Amount::ZERO);
+
+        inhibited.thresholds.fill(threshold);
+
+        let mut free = inhibited.clone();
+
+        inhibited.set_lateral_inhibition(Amount::from_num(0.5));
+
+        for lobe in [&mut inhibited, &mut free] {
+            lobe.apply_input(&[Amount::from_num(0.7), Amount::from_num(0.65)]);
+            lobe.tick(1.0);
+            lobe.apply_input(&[Amount::from_num(0.1), Amount::from_num(0.1)]);
+        }
+
+        assert!(free.value_at(0, 0).unwrap() >= threshold);
+        assert!(free.value_at(0, 1).unwrap() >= threshold);
+        assert!(inhibited.value_at(0, 0).unwrap() >= threshold);
+        assert!(inhibited.value_at(0, 1).unwrap() < threshold);
+    }
+
+    #[test]
+    fn elapsed_time_adds_up_the_ticks() {
+        let mut lobe = chain();
+
+        lobe.tick(0.25);
+        lobe.tick(0.5);
+        lobe.tick_n(2, 0.125);
+
+        assert_eq!(lobe.elapsed_secs(), 1.0);
+
+        lobe.reset_elapsed_secs();
+
+        assert_eq!(lobe.elapsed_secs(), 0.0);
+    }
+
+    #[test]
+    fn region_input_reaches_only_that_region() {
+        let mut lobe = Lobe::<Amount>::new(4, 3, Amount::ZERO);
+
+        lobe.apply_input_to_region(1, 1..3, &[Amount::ONE, Amount::from_num(2), Amount::MAX]);
+
+        for col in 0..=3 {
+            for row in 0..4 {
+                let expected = match (col, row) {
+                    (1, 1) => 1,
+                    (1, 2) => 2,
+                    _ => 0,
+                };
+
+                assert_eq!(lobe.value_at(col, row).unwrap(), Amount::from_num(expected));
+            }
+        }
+
+        assert!(lobe
+            .try_apply_input_to_region(4, 0..1, &[Amount::ONE])
+            .is_err());
+        assert!(lobe
+            .try_apply_input_to_region(0, 3..5, &[Amount::ONE])
+            .is_err());
+    }
+
+    #[test]
+    #[should_panic]
+    fn region_input_past_the_breadth_panics() {
+        Lobe::<Amount>::new(2, 1, Amount::ZERO).apply_input_to_region(0, 1..3, &[]);
+    }
+
+    #[test]
+    fn weights_between_neighbors_read_back() {
+        let mut lobe = Lobe::<Amount>::new(3, 2, Amount::ZERO);
+
+        *lobe.weight_between_mut(0, 1, 2).unwrap() = Amount::from_num(0.75);
+        *lobe.weight_between_mut(1, 2, 2).unwrap() = -Amount::ONE;
+
+        assert_eq!(lobe.weight_between(0, 1, 2), Some(Amount::from_num(0.75)));
+        assert_eq!(lobe.weight_between(1, 2, 2), Some(-Amount::ONE));
+        assert_eq!(lobe.weight_between(0, 1, 1), Some(Amount::ZERO));
+        assert_eq!(
+            lobe.weights.iter().filter(|w| **w != Amount::ZERO).count(),
+            2
+        );
+
+        assert_eq!(lobe.weight_between(0, 0, 2), None);
+        assert_eq!(lobe.weight_between(0, 2, 3), None);
+        assert_eq!(lobe.weight_between(2, 0, 0), None);
+        assert!(lobe.weight_between_mut(0, 2, 0).is_none());
+    }
+
+    #[test]
+    fn gradient_steps_move_each_parameter_by_its_own_entry() {
+        let mut lobe = chain();
+        let before = lobe.all_parameters_owned();
+        let grad: Vec<Amount> = (0..before.len())
+            .map(|index| Amount::from_num(index) / 8)
+            .collect();
+
+        lobe.apply_gradient(&grad, Amount::from_num(0.5)).unwrap();
+
+        for (after, (before, grad)) in izip!(lobe.all_parameters_owned(), izip!(before, &grad)) {
+            assert_eq!(after, before - *grad / 2);
+        }
+
+        let params = lobe.all_parameters_owned();
+
+        assert_eq!(
+            lobe.apply_gradient(&grad[1..], Amount::ONE),
+            Err(SizeError {
+                expected: grad.len(),
+                got: grad.len() - 1,
+            })
+        );
+        assert_eq!(lobe.all_parameters_owned(), params);
+    }
+
+    #[test]
+    fn indexing_matches_the_value_columns() {
+        let mut lobe = Lobe::<Amount>::new(2, 2, Amount::ZERO);
+
+        lobe[(0, 1)] = Amount::ONE;
+        lobe[(2, 0)] = Amount::from_num(-0.5);
+
+        assert_eq!(lobe.value_column_ref(0), [Amount::ZERO, Amount::ONE]);
+        assert_eq!(
+            lobe.value_column_ref(2),
+            [Amount::from_num(-0.5), Amount::ZERO]
+        );
+        assert_eq!(lobe[(0, 1)], Amount::ONE);
+        assert_eq!(Some(lobe[(2, 0)]), lobe.value_at(2, 0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn indexing_past_the_output_column_panics() {
+        let _ = Lobe::<Amount>::new(2, 2, Amount::ZERO)[(3, 0)];
+    }
+
+    #[test]
+    fn fire_raster_compares_values_with_thresholds() {
+        let mut lobe = Lobe::<Amount>::new(2, 2, Amount::ZERO);
+
+        lobe.thresholds.fill(Amount::from_num(0.5));
+        *lobe.threshold_at_mut(1, 1).unwrap() = Amount::from_num(-1);
+        lobe[(0, 0)] = Amount::from_num(0.5);
+        lobe[(0, 1)] = Amount::from_num(0.25);
+        lobe[(1, 0)] = Amount::ONE;
+        lobe[(1, 1)] = Amount::from_num(-0.5);
+        lobe[(2, 1)] = Amount::from_num(0.125);
+
+        assert_eq!(lobe.fire_raster(), [true, false, true, true, false, true]);
+    }
+
+    #[test]
+    fn zero_sized_lobes_tick_without_panicking() {
+        let mut narrow = Lobe::<Amount>::new(0, 3, Amount::ZERO);
+
+        narrow.apply_input(&[]);
+        narrow.tick(1.0);
+
+        assert!(narrow.get_output().is_empty());
+        assert_eq!(narrow.values_chunked().count(), 0);
+
+        let mut shallow = Lobe::<Amount>::new(2, 0, Amount::ZERO);
+
+        shallow.apply_input(&[Amount::ONE, Amount::ZERO]);
+        shallow.tick(1.0);
+
+        assert_eq!(shallow.output_size(), 2);
+        assert_eq!(shallow.get_output(), [Amount::ONE, Amount::ZERO]);
+    }
+
+    #[test]
+    fn zero_durations_leave_the_lobe_untouched() {
+        let mut lobe = chain();
+
+        lobe.apply_input(&[Amount::ONE]);
+
+        let before = lobe.clone();
+
+        lobe.tick(0.0);
+
+        assert_eq!(lobe, before);
+        assert_eq!(lobe.counted_ticks(), 0);
+    }
+
+    #[test]
+    fn negative_durations_are_rejected() {
+        let mut lobe = chain();
+
+        assert_eq!(lobe.try_tick(-0.5), Err(SpikerError::InvalidDuration(-0.5)));
+        assert_eq!(lobe, chain());
+    }
+
+    #[test]
+    #[should_panic(expected = "tick duration must not be negative")]
+    fn ticking_backwards_panics() {
+        chain().tick(-0.5);
+    }
+
+    #[test]
+    fn mapping_transforms_every_parameter_of_a_kind() {
+        let original =
+            Lobe::<Amount>::randomized(3, 2, Amount::ZERO, &mut SplitMix64::seed_from_u64(8));
+        let mut lobe = original.clone();
+
+        lobe.map_weights(|weight| weight * 2);
+        lobe.map_thresholds(|threshold| -threshold);
+
+        for (after, before) in izip!(&lobe.weights, &original.weights) {
+            assert_eq!(*after, *before * 2);
+        }
+
+        for (after, before) in izip!(&lobe.thresholds, &original.thresholds) {
+            assert_eq!(*after, -*before);
+        }
+
+        assert_eq!(lobe.strengths, original.strengths);
+    }
+
+    #[test]
+    fn quantizing_snaps_weights_to_the_grid() {
+        let mut lobe =
+            Lobe::<Amount>::randomized(3, 2, Amount::ZERO, &mut SplitMix64::seed_from_u64(9));
+
+        *lobe.weight_between_mut(0, 0, 0).unwrap() = Amount::from_num(-3);
+
+        let original = lobe.clone();
+        let grid = [-1.0, -0.5, 0.0, 0.5].map(Amount::from_num);
+        let max_error = lobe.quantize_weights(4, (grid[0], grid[3]));
+
+        assert!(lobe.weights.iter().all(|weight| grid.contains(weight)));
+        assert_eq!(lobe.weight_between(0, 0, 0), Some(-Amount::ONE));
+        assert_eq!(max_error, Amount::from_num(2));
+
+        for (after, before) in izip!(&lobe.weights, &original.weights) {
+            assert!(
+                (*after - *before).abs() <= Amount::from_num(0.25)
+                    || *before < grid[0]
+                    || *before > grid[3]
+            );
+        }
+    }
+
+    #[test]
+    fn streams_yield_one_output_per_frame() {
+        let mut lobe = chain();
+
+        lobe.map_weights(|_| Amount::ONE);
+        lobe.map_strengths(|_| Amount::ONE);
+
+        let frames = vec![vec![Amount::ONE], vec![Amount::ZERO], vec![Amount::ZERO]];
+        let outputs: Vec<_> = lobe.process_stream(frames.into_iter(), 1.0).collect();
+
+        assert_eq!(outputs.len(), 3);
+        assert_eq!(outputs[0], [Amount::ZERO]);
+        assert_eq!(outputs[1], [Amount::ONE]);
+        assert_eq!(lobe.counted_ticks(), 3);
+        assert_eq!(lobe.elapsed_secs(), 3.0);
+    }
+
+    #[test]
+    fn tied_edits_through_any_column_reach_every_column() {
+        let mut lobe = Lobe::<Amount>::new(2, 3, Amount::ZERO);
+
+        lobe.set_tied(true);
+        lobe.threshold_column_mut(2)[1] = Amount::from_num(0.5);
+        *lobe.weight_between_mut(1, 0, 0).unwrap() = Amount::ONE;
+
+        for col in 0..3 {
+            assert_eq!(lobe.threshold_at(col, 1), Some(Amount::from_num(0.5)));
+            assert_eq!(lobe.weight_between(col, 0, 0), Some(Amount::ONE));
+        }
+    }
+
+    #[test]
+    fn tied_ticks_use_edits_through_any_column() {
+        let mut tied = chain();
+        let mut untied = chain();
+
+        tied.set_tied(true);
+        tied.strength_column_mut(1)[0] = Amount::ONE;
+        *tied.weight_between_mut(1, 0, 0).unwrap() = Amount::from_num(0.75);
+
+        for col in 0..2 {
+            untied.strength_column_mut(col)[0] = Amount::ONE;
+            *untied.weight_between_mut(col, 0, 0).unwrap() = Amount::from_num(0.75);
+        }
+
+        for lobe in [&mut tied, &mut untied] {
+            for _ in 0..3 {
+                lobe.apply_input(&[Amount::ONE]);
+                lobe.tick(1.0);
+            }
+        }
+
+        assert_ne!(untied.get_output(), [Amount::ZERO]);
+        assert_eq!(tied.get_output(), untied.get_output());
+    }
+
+    #[test]
+    fn tied_parameters_only_cover_the_template() {
+        let mut lobe = Lobe::<Amount>::new(2, 3, Amount::ZERO);
+
+        assert_eq!(lobe.all_parameters_owned().len(), 6 + 6 * 3 + 6 + 2);
+
+        lobe.set_tied(true);
+
+        assert_eq!(lobe.all_parameters_owned().len(), 2 + 2 * 3 + 2 + 2);
+        assert_eq!(
+            lobe.all_parameters_slices().concat().len(),
+            2 + 2 * 3 + 2 + 2
+        );
+    }
+
+    #[test]
+    fn untying_keeps_the_template_edits() {
+        let mut lobe = Lobe::<Amount>::new(2, 3, Amount::ZERO);
+
+        lobe.set_tied(true);
+        lobe.threshold_column_mut(2)[0] = Amount::ONE;
+        lobe.set_tied(false);
+        lobe.threshold_column_mut(0)[0] = Amount::ZERO;
+
+        assert_eq!(lobe.threshold_at(0, 0), Some(Amount::ZERO));
+        assert_eq!(lobe.threshold_at(1, 0), Some(Amount::ONE));
+        assert_eq!(lobe.threshold_at(2, 0), Some(Amount::ONE));
+    }
+
+    #[test]
+    fn tied_lobes_cross_over_by_template() {
+        let mut left = Lobe::<Amount>::new(2, 3, Amount::ZERO);
+        let mut right = left.clone();
+
+        left.set_tied(true);
+        right.set_tied(true);
+        right.threshold_column_mut(0).fill(Amount::ONE);
+
+        let mut mask = vec![true; left.all_parameters_owned().len()];
+        mask[1] = false;
+
+        let child = left.crossover(&right, &mask).unwrap();
+
+        assert!(child.tied());
+        assert_eq!(child.threshold_at(2, 0), left.threshold_at(2, 0));
+        assert_eq!(child.threshold_at(2, 1), Some(Amount::ONE));
+        assert_eq!(
+            left.crossover(&Lobe::new(2, 3, Amount::ZERO), &mask),
+            Err(GeneticError::LayoutMismatch)
+        );
+    }
+
+    #[test]
+    fn observers_see_the_output_of_every_tick() {
+        let mut lobe = chain();
+        let mut total = Amount::ZERO;
+        let mut calls = 0;
+
+        lobe.map_weights(|_| Amount::ONE);
+        lobe.map_strengths(|_| Amount::ONE);
+
+        let mut plain = lobe.clone();
+        let mut expected = Amount::ZERO;
+
+        for _ in 0..3 {
+            lobe.apply_input(&[Amount::ONE]);
+            lobe.tick_with(1.0, |output| {
+                total += output[0];
+                calls += 1;
+            });
+
+            plain.apply_input(&[Amount::ONE]);
+            plain.tick(1.0);
+            expected += plain.get_output()[0];
+        }
+
+        assert_eq!(calls, 3);
+        assert_eq!(total, expected);
+        assert!(total > Amount::ZERO);
+    }
+
+    #[test]
+    fn sparsifying_zeroes_the_smallest_weights() {
+        let mut lobe =
+            Lobe::<Amount>::randomized(4, 3, Amount::ZERO, &mut SplitMix64::seed_from_u64(10));
+        let original = lobe.clone();
+
+        lobe.sparsify(0.5);
+
+        assert_eq!(lobe.sparsity(), 0.5);
+
+        let smallest_kept = lobe
+            .weights
+            .iter()
+            .filter(|w| **w != Amount::ZERO)
+            .min()
+            .unwrap();
+
+        for (after, before) in izip!(&lobe.weights, &original.weights) {
+            if *after == Amount::ZERO {
+                assert!(before <= smallest_kept);
+            } else {
+                assert_eq!(after, before);
+            }
+        }
+
+        assert_eq!(Lobe::<Amount>::new(2, 2, Amount::ZERO).sparsity(), 1.0);
+    }
+
+    #[test]
+    fn tied_lobes_sparsify_every_column_alike() {
+        let mut lobe =
+            Lobe::<Amount>::randomized(4, 3, Amount::ZERO, &mut SplitMix64::seed_from_u64(11));
+
+        lobe.set_tied(true);
+        lobe.sparsify(0.5);
+
+        assert_eq!(lobe.sparsity(), 0.5);
+
+        for col in 1..3 {
+            assert_eq!(lobe.weights[col * 12..(col + 1) * 12], lobe.weights[..12]);
+        }
+    }
+
+    #[test]
+    fn refractory_neurons_stay_at_the_reset_value() {
+        let reset = Amount::from_num(-0.25);
+        let mut lobe = Lobe::<Amount>::new(1, 1, Amount::ZERO).with_refractory_ticks(2);
+
+        lobe.map_thresholds(|_| Amount::from_num(0.5));
+        lobe.set_reset_value(reset);
+
+        let mut values = vec![];
+
+        for _ in 0..4 {
+            lobe.apply_input(&[Amount::ONE]);
+            values.push(lobe.value_at(0, 0).unwrap());
+            lobe.tick(1.0);
+        }
+
+        assert_eq!(values, [Amount::ONE, reset, reset, reset + Amount::ONE]);
+        assert_eq!(lobe.spike_counts()[0], 2);
+    }
+
+    #[test]
+    fn negative_reset_dips_below_zero_and_recovers() {
+        let mut lobe = Lobe::<Amount>::new(1, 1, Amount::from_num(0.5));
+
+        lobe.map_thresholds(|_| Amount::from_num(0.5));
+        lobe.set_reset_value(Amount::from_num(-0.5));
+        lobe.apply_input(&[Amount::ONE]);
+        lobe.tick(1.0);
+
+        let dipped = lobe.value_at(0, 0).unwrap();
+
+        lobe.tick(1.0);
+
+        let recovering = lobe.value_at(0, 0).unwrap();
+
+        assert_eq!(dipped, Amount::from_num(-0.25));
+        assert!(dipped < recovering && recovering < Amount::ZERO);
+    }
+
+    #[test]
+    fn reset_below_the_floor_lowers_it() {
+        let mut lobe = Lobe::<Amount>::new(1, 1, Amount::ZERO);
+
+        lobe.set_reset_value(Amount::ONE);
+        assert_eq!(lobe.min_value(), Amount::ZERO);
+
+        lobe.set_reset_value(-Amount::ONE);
+        assert_eq!(lobe.min_value(), -Amount::ONE);
+    }
+
+    #[test]
+    fn reset_is_a_parameter() {
+        let mut lobe = Lobe::<Amount>::new(2, 1, Amount::ZERO);
+
+        lobe.set_reset_value(Amount::from_num(-0.5));
+
+        let params = lobe.all_parameters_owned();
+        let loaded = Lobe::<Amount>::from_parameters(lobe.get_dims(), &params);
+
+        // The falloff is followed by the reset value.
+        assert_eq!(params.len(), 2 * 5 + 2);
+        assert_eq!(params[params.len() - 1], Amount::from_num(-0.5));
+        assert_eq!(loaded.reset_value(), Amount::from_num(-0.5));
+        assert_eq!(loaded.min_value(), Amount::from_num(-0.5));
+        assert_eq!(loaded.all_parameters_owned(), params);
+
+        lobe.freeze(ParamGroup::Reset);
+        lobe.apply_gradient(&vec![Amount::ONE; params.len()], Amount::ONE)
+            .unwrap();
+
+        assert_eq!(lobe.reset_value(), Amount::from_num(-0.5));
+    }
+
+    /// Pins down the outputs of a fixed run, as computed before values were
+    /// double buffered.
+    #[test]
+    fn double_buffered_ticks_match_the_recorded_run() {
+        let mut lobe = Lobe::<Amount>::randomized(
+            3,
+            2,
+            Amount::from_num(0.5),
+            &mut SplitMix64::seed_from_u64(90),
+        );
+
+        lobe.set_refractory_ticks(1);
+        lobe.set_reset_value(Amount::from_num(0.125));
+
+        let inputs = [
+            [1.0, 0.0, 0.5],
+            [0.25, 1.0, 0.0],
+            [0.0, 0.0, 0.0],
+            [1.5, 1.5, 1.5],
+            [0.0, 0.75, 0.0],
+        ];
+        let expected = [
+            [0, 0, 0],
+            [0, 0, 0],
+            [12, 24, 0],
+            [9, 18, 0],
+            [230, 171, 270],
+        ];
+
+        for (input, expected) in izip!(inputs, expected) {
+            lobe.apply_input(&input.map(Amount::from_num));
+            lobe.tick(0.5);
+
+            assert_eq!(lobe.get_output(), expected.map(Amount::from_bits));
+        }
+
+        assert_eq!(
+            lobe.values[..6],
+            [96, 96, 96, 72, 96, 96].map(Amount::from_bits)
+        );
+        assert_eq!(lobe.spike_counts(), [2, 2, 2, 1, 1, 1]);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn lobes_cross_threads() {
+        let mut lobe = chain();
+
+        lobe.apply_input(&[Amount::ONE]);
+
+        let shared = &lobe;
+        let ticked = std::thread::scope(|scope| {
+            let [first, second] = [(); 2].map(|_| {
+                scope.spawn(move || {
+                    let mut lobe = shared.clone();
+
+                    lobe.tick(1.0);
+                    lobe
+                })
+            });
+
+            [first.join().unwrap(), second.join().unwrap()]
+        });
+
+        lobe.tick(1.0);
+
+        assert_eq!(ticked, [lobe.clone(), lobe]);
+    }
+
+    #[test]
+    fn global_gain_scales_every_spike() {
+        let mut plain = single_spike(Amount::ONE);
+        let mut damped = single_spike(Amount::ONE);
+        let mut weak = single_spike(Amount::ONE);
+
+        damped.set_global_gain(Amount::from_num(0.5));
+        weak.map_strengths(|strength| strength / 2);
+
+        for lobe in [&mut plain, &mut damped, &mut weak] {
+            lobe.tick(1.0);
+        }
+
+        assert_eq!(plain.get_output(), [Amount::from_num(1.5)]);
+        assert_eq!(damped.get_output(), [Amount::ONE]);
+        assert_eq!(damped.get_output(), weak.get_output());
+    }
+
+    #[test]
+    fn firing_rates_settle_at_the_driven_rate() {
+        let mut lobe = Lobe::<f64>::new(1, 1, 0.0);
+
+        lobe.map_thresholds(|_| 0.5);
+        lobe.set_rate_alpha(Some(0.1));
+
+        for tick in 0..=200 {
+            if tick % 2 == 0 {
+                lobe.apply_input(&[1.0]);
+            }
+
+            lobe.tick(1.0);
+        }
+
+        // A neuron firing every other tick has just fired, so its rate sits
+        // at the top of its oscillation around a half.
+        let rate = lobe.firing_rates()[0];
+
+        assert!(
+            (rate - 0.1 / (1.0 - 0.9 * 0.9)).abs() < 1e-6,
+            "rate is {}",
+            rate
+        );
+        assert_eq!(lobe.rate_alpha(), Some(0.1));
+
+        lobe.set_rate_alpha(None);
+
+        assert!(lobe.firing_rates().is_empty());
+    }
+
+    #[test]
+    fn any_column_reads_as_an_output() {
+        let mut lobe = Lobe::<Amount>::new(2, 3, Amount::ZERO);
+        let input = [Amount::ONE, Amount::from_num(0.5)];
+
+        lobe.apply_input(&input);
+        lobe[(1, 1)] = Amount::from_num(0.25);
+
+        assert_eq!(lobe.column_output(0), Some(&input[..]));
+        assert_eq!(
+            lobe.column_output(1),
+            Some(&[Amount::ZERO, Amount::from_num(0.25)][..])
+        );
+        assert_eq!(lobe.column_output(3), Some(lobe.get_output()));
+        assert_eq!(lobe.column_output(4), None);
+    }
+
+    #[test]
+    fn inputs_are_normalized_per_channel() {
+        let mut lobe = Lobe::<Amount>::new(2, 1, Amount::ZERO);
+
+        lobe.set_input_offset(Some(vec![Amount::from_num(0.5), Amount::ZERO]))
+            .unwrap();
+        lobe.set_input_scale(Some(vec![Amount::ONE, Amount::from_num(2)]))
+            .unwrap();
+        lobe.apply_input(&[Amount::ONE, Amount::from_num(0.25)]);
+
+        assert_eq!(
+            lobe.value_column_ref(0),
+            [Amount::from_num(0.5), Amount::from_num(0.5)]
+        );
+
+        lobe.apply_input_at(1, Amount::ONE);
+
+        assert_eq!(lobe[(0, 1)], Amount::from_num(2.5));
+        assert_eq!(
+            lobe.set_input_scale(Some(vec![Amount::ONE])),
+            Err(ParamError::LengthMismatch {
+                expected: 2,
+                got: 1,
+            })
+        );
+        assert_eq!(
+            lobe.input_scale(),
+            Some(&[Amount::ONE, Amount::from_num(2)][..])
+        );
+    }
+
+    #[test]
+    fn clear_below_zeroes_small_values_of_either_sign() {
+        let mut lobe = Lobe::<Amount>::new(2, 1, Amount::ZERO);
+        let small = Amount::from_num(0.01);
+
+        lobe.values
+            .copy_from_slice(&[small, -small, Amount::ONE, -Amount::ONE]);
+        lobe.clear_below(Amount::from_num(0.1));
+
+        assert_eq!(
+            lobe.values,
+            [Amount::ZERO, Amount::ZERO, Amount::ONE, -Amount::ONE]
+        );
+    }
+
+    #[test]
+    fn clear_below_keeps_the_most_negative_value() {
+        let mut lobe = Lobe::<Amount>::new(1, 1, Amount::ZERO);
+
+        lobe.values[0] = Amount::MIN;
+        lobe.clear_below(Amount::from_num(0.1));
+        lobe.clear_below(Amount::MIN);
+
+        assert_eq!(lobe.values[0], Amount::MIN);
+    }
+
+    #[test]
+    fn denoising_clears_what_leaking_leaves_behind() {
+        let mut noisy = Lobe::<Amount>::new(1, 1, Amount::from_num(0.5));
+
+        noisy.map_thresholds(|_| Amount::ONE);
+        noisy.values.fill(Amount::from_num(0.01));
+
+        let mut denoised = noisy.clone();
+
+        denoised.set_denoise_epsilon(Some(Amount::from_num(0.05)));
+        noisy.tick(1.0);
+        denoised.tick(1.0);
+
+        assert!(noisy.values.iter().all(|value| *value > Amount::ZERO));
+        assert!(denoised.values.iter().all(|value| *value == Amount::ZERO));
     }
 }
@@ -0,0 +1,338 @@
+use super::{Connectivity, Lobe};
+use crate::types::Scalar;
+use alloc::vec::Vec;
+use core::fmt;
+use core::mem::size_of;
+
+/// Identifies the binary Lobe format.
+const MAGIC: &[u8; 4] = b"SPKL";
+
+/// The version of the binary Lobe format written by [Lobe::to_bytes].
+const VERSION: u8 = 1;
+
+/// Set in the flags byte if per-neuron falloffs follow the strengths.
+const FLAG_FALLOFFS: u8 = 1;
+
+/// Set in the flags byte if per-neuron biases follow the falloffs.
+const FLAG_BIAS: u8 = 2;
+
+/// An error found while decoding a Lobe with [Lobe::from_bytes].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DecodeError {
+    /// The bytes do not start with the format's magic number.
+    BadMagic,
+
+    /// The bytes were written by an unknown version of the format.
+    UnsupportedVersion(u8),
+
+    /// The bytes were written for another scalar type, identified by its
+    /// [Scalar::TAG].
+    WrongScalar { expected: u8, got: u8 },
+
+    /// The connectivity is unknown, or a forward connectivity has an even
+    /// number of neighbors.
+    BadConnectivity,
+
+    /// The flags byte has unknown flags set, or biases without per-neuron
+    /// falloffs.
+    BadFlags(u8),
+
+    /// The bytes end before all the parameters the header calls for.
+    Truncated,
+
+    /// Bytes are left over after the last parameter.
+    TrailingBytes { extra: usize },
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecodeError::BadMagic => write!(f, "not a binary Lobe"),
+            DecodeError::UnsupportedVersion(version) => {
+                write!(f, "unsupported binary Lobe version {}", version)
+            }
+            DecodeError::WrongScalar { expected, got } => {
+                write!(f, "expected scalar type tag {}, but got {}", expected, got)
+            }
+            DecodeError::BadConnectivity => write!(f, "invalid connectivity"),
+            DecodeError::BadFlags(flags) => write!(f, "invalid flags {:#04x}", flags),
+            DecodeError::Truncated => write!(f, "unexpected end of data"),
+            DecodeError::TrailingBytes { extra } => {
+                write!(f, "{} unexpected bytes after the parameters", extra)
+            }
+        }
+    }
+}
+
+impl core::error::Error for DecodeError {}
+
+/// Reads fields from the front of a byte slice.
+struct Reader<'a>(&'a [u8]);
+
+impl<'a> Reader<'a> {
+    /// Takes the next `N` bytes.
+    fn take<const N: usize>(&mut self) -> Result<[u8; N], DecodeError> {
+        if self.0.len() < N {
+            return Err(DecodeError::Truncated);
+        }
+
+        let (head, rest) = self.0.split_at(N);
+
+        self.0 = rest;
+
+        Ok(head.try_into().unwrap())
+    }
+
+    fn u8(&mut self) -> Result<u8, DecodeError> {
+        Ok(self.take::<1>()?[0])
+    }
+
+    fn u64(&mut self) -> Result<u64, DecodeError> {
+        Ok(u64::from_le_bytes(self.take()?))
+    }
+
+    /// Reads a scalar's bits, stored in as many bytes as the type takes up.
+    fn scalar<S: Scalar>(&mut self) -> Result<S, DecodeError> {
+        let size = size_of::<S>();
+
+        if self.0.len() < size {
+            return Err(DecodeError::Truncated);
+        }
+
+        let (head, rest) = self.0.split_at(size);
+        let mut bits = [0; 8];
+
+        bits[..size].copy_from_slice(head);
+        self.0 = rest;
+
+        Ok(S::from_bits(u64::from_le_bytes(bits)))
+    }
+
+    /// Reads a dimension, which cannot possibly be larger than the data left.
+    fn dim(&mut self) -> Result<usize, DecodeError> {
+        usize::try_from(self.u64()?).map_err(|_| DecodeError::Truncated)
+    }
+}
+
+impl<S: Scalar> Lobe<S> {
+    /// Encodes the Lobe's parameters in a compact, self-describing binary
+    /// format, which [Lobe::from_bytes] reads back.
+    ///
+    /// A header holds a magic number, the format version, the scalar type's
+    /// [Scalar::TAG], the dimensions, the shared falloff, the reset value,
+    /// the connectivity and which optional parameters are set. The
+    /// thresholds, weights and strengths follow, then the per-neuron
+    /// falloffs and biases if set. Every scalar is stored exactly, as its
+    /// [Scalar::to_bits] in little-endian order, taking as many bytes as the
+    /// type does. Only the parameters are saved: values, traces and settings
+    /// are not. While tied, every column's parameters are saved, copied from
+    /// the template.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut params = self.untie_parameters(self.all_parameters_owned());
+        let area = self.thresholds.len();
+        // The header holds the falloff and reset value, which come next.
+        let falloff = params.remove(area * 2 + self.weights.len());
+        params.remove(area * 2 + self.weights.len());
+
+        let (connectivity, neighbors) = match self.connectivity {
+            Connectivity::Forward { neighbors } => (0, neighbors),
+            Connectivity::Moore8 => (1, 8),
+        };
+
+        let mut flags = 0;
+
+        if self.falloff_per_neuron.is_some() || self.bias.is_some() {
+            flags |= FLAG_FALLOFFS;
+        }
+
+        if self.bias.is_some() {
+            flags |= FLAG_BIAS;
+        }
+
+        let size = size_of::<S>();
+        let mut bytes = Vec::with_capacity(32 + (params.len() + 2) * size);
+        let push_scalar = |bytes: &mut Vec<u8>, value: S| {
+            bytes.extend(&value.to_bits().to_le_bytes()[..size]);
+        };
+
+        bytes.extend(MAGIC);
+        bytes.push(VERSION);
+        bytes.push(S::TAG);
+        bytes.extend((self.dims.0 as u64).to_le_bytes());
+        bytes.extend((self.dims.1 as u64).to_le_bytes());
+        push_scalar(&mut bytes, falloff);
+        push_scalar(&mut bytes, self.reset_value);
+        bytes.push(connectivity);
+        bytes.extend((neighbors as u64).to_le_bytes());
+        bytes.push(flags);
+
+        for param in params {
+            push_scalar(&mut bytes, param);
+        }
+
+        bytes
+    }
+
+    /// Decodes a Lobe written by [Lobe::to_bytes], checking the header and
+    /// that the data holds exactly as many parameters as it calls for.
+    ///
+    /// Everything but the parameters starts out at its default, as in
+    /// [Lobe::new]. Bytes written for another scalar type are rejected.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, DecodeError> {
+        let mut reader = Reader(bytes);
+
+        if &reader.take::<4>()? != MAGIC {
+            return Err(DecodeError::BadMagic);
+        }
+
+        match reader.u8()? {
+            VERSION => {}
+            version => return Err(DecodeError::UnsupportedVersion(version)),
+        }
+
+        let tag = reader.u8()?;
+
+        if tag != S::TAG {
+            return Err(DecodeError::WrongScalar {
+                expected: S::TAG,
+                got: tag,
+            });
+        }
+
+        let dims = (reader.dim()?, reader.dim()?);
+        let falloff = reader.scalar()?;
+        let reset_value = reader.scalar()?;
+
+        let connectivity = match (reader.u8()?, reader.u64()?) {
+            (0, neighbors) if neighbors % 2 == 1 => Connectivity::Forward {
+                neighbors: usize::try_from(neighbors).map_err(|_| DecodeError::Truncated)?,
+            },
+            (1, 8) => Connectivity::Moore8,
+            _ => return Err(DecodeError::BadConnectivity),
+        };
+
+        // Biases are always preceded by per-neuron falloffs.
+        let optional = match reader.u8()? {
+            0 => 0,
+            FLAG_FALLOFFS => 1,
+            flags if flags == FLAG_FALLOFFS | FLAG_BIAS => 2,
+            flags => return Err(DecodeError::BadFlags(flags)),
+        };
+
+        // Sizes too large to compute cannot fit in the data either.
+        let area = dims.0.checked_mul(dims.1).ok_or(DecodeError::Truncated)?;
+        let neurons = area.checked_add(dims.1).ok_or(DecodeError::Truncated)?;
+
+        let count = (connectivity.neighbors().checked_add(2))
+            .and_then(|per_neuron| area.checked_mul(per_neuron))
+            .and_then(|count| count.checked_add(neurons.checked_mul(optional)?))
+            .ok_or(DecodeError::Truncated)?;
+
+        let expected = count
+            .checked_mul(size_of::<S>())
+            .ok_or(DecodeError::Truncated)?;
+
+        match reader.0.len() {
+            len if len < expected => return Err(DecodeError::Truncated),
+            len if len > expected => {
+                return Err(DecodeError::TrailingBytes {
+                    extra: len - expected,
+                })
+            }
+            _ => {}
+        }
+
+        let mut params = Vec::with_capacity(count + 2);
+
+        for _ in 0..count {
+            params.push(reader.scalar()?);
+        }
+
+        let header = area * (connectivity.neighbors() + 2);
+        params.splice(header..header, [falloff, reset_value]);
+
+        Ok(Self::try_from_parameters_with(dims, connectivity, &params)
+            .expect("the parameter count was already checked"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::SplitMix64;
+    use crate::types::{Amount, I32F32};
+    use alloc::vec;
+
+    #[test]
+    fn round_trips_parameters() {
+        let mut rng = SplitMix64::seed_from_u64(7);
+        let mut lobe = Lobe::<Amount>::randomized(3, 2, Amount::from_num(0.25), &mut rng);
+        let neurons = lobe.values.len();
+
+        lobe.set_falloff_per_neuron(Some(vec![Amount::from_num(0.5); neurons]))
+            .unwrap();
+        lobe.set_bias(Some(vec![Amount::from_num(0.125); neurons]))
+            .unwrap();
+        lobe.set_reset_value(Amount::from_num(-0.5));
+
+        assert_eq!(Lobe::from_bytes(&lobe.to_bytes()), Ok(lobe));
+    }
+
+    #[test]
+    fn stores_scalars_exactly_in_their_own_size() {
+        let mut lobe = Lobe::<f32>::new(1, 1, 0.0);
+
+        lobe.set_thresholds_from(&[f32::INFINITY]).unwrap();
+
+        let bytes = lobe.to_bytes();
+        let decoded = Lobe::<f32>::from_bytes(&bytes).unwrap();
+
+        // The header, then the falloff, reset value and five parameters of
+        // four bytes.
+        assert_eq!(bytes.len(), 32 + 7 * 4);
+        assert_eq!(decoded.threshold_at(0, 0), Some(f32::INFINITY));
+    }
+
+    #[test]
+    fn round_trips_wide_fixed_point_exactly() {
+        let mut lobe = Lobe::<I32F32>::new(1, 1, I32F32::ZERO);
+        let threshold = I32F32::from_num(1_000_000_000) + I32F32::DELTA;
+
+        lobe.set_thresholds_from(&[threshold]).unwrap();
+
+        let decoded = Lobe::<I32F32>::from_bytes(&lobe.to_bytes()).unwrap();
+
+        assert_eq!(decoded.threshold_at(0, 0), Some(threshold));
+        assert_eq!(decoded, lobe);
+    }
+
+    #[test]
+    fn rejects_another_scalar_type() {
+        let bytes = Lobe::<Amount>::new(1, 1, Amount::ZERO).to_bytes();
+
+        assert_eq!(
+            Lobe::<f64>::from_bytes(&bytes),
+            Err(DecodeError::WrongScalar {
+                expected: f64::TAG,
+                got: Amount::TAG,
+            })
+        );
+    }
+
+    #[test]
+    fn rejects_truncated_and_trailing_bytes() {
+        let mut bytes = Lobe::<Amount>::new(2, 2, Amount::ZERO).to_bytes();
+
+        bytes.push(0);
+        assert_eq!(
+            Lobe::<Amount>::from_bytes(&bytes),
+            Err(DecodeError::TrailingBytes { extra: 1 })
+        );
+
+        bytes.truncate(bytes.len() - 2);
+        assert_eq!(
+            Lobe::<Amount>::from_bytes(&bytes),
+            Err(DecodeError::Truncated)
+        );
+    }
+}
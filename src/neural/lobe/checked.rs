@@ -0,0 +1,353 @@
+use super::Lobe;
+use crate::neural::base::NeuralObject;
+use crate::types::Scalar;
+use alloc::vec::Vec;
+use core::fmt;
+
+/// A tick that would overflow the range of the Lobe's scalar type.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OverflowError {
+    /// Flat index, into the values, of the first neuron that would overflow.
+    pub neuron: usize,
+}
+
+impl fmt::Display for OverflowError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "neuron {} would overflow during the tick", self.neuron)
+    }
+}
+
+impl core::error::Error for OverflowError {}
+
+impl<S: Scalar> Lobe<S> {
+    /// Like [NeuralObject::tick], but first checks that no neuron's value
+    /// would overflow at any point of the tick.
+    ///
+    /// Fixed-point arithmetic wraps around silently in release builds, which
+    /// turns an overflowing neuron into garbage. If any neuron would
+    /// overflow, the Lobe is left untouched and the first such neuron is
+    /// reported instead.
+    ///
+    /// With [super::PropagationMode::Full], every wave of propagation is
+    /// checked, along with what it leaves for the next. With
+    /// [super::StochasticFiring], the check draws the same random numbers
+    /// the tick will. With a conduction delay, see [Lobe::set_delay_waves],
+    /// the spikes checked are the ones arriving, including those already in
+    /// flight.
+    pub fn tick_checked(&mut self, duration_secs: f64) -> Result<(), OverflowError> {
+        self.check_tick(S::from_f64(duration_secs))?;
+        self.tick(duration_secs);
+
+        Ok(())
+    }
+
+    /// Replays a tick with checked arithmetic, from the bias through every
+    /// wave of propagation to the decay, without modifying the Lobe.
+    fn check_tick(&self, duration_secs: S) -> Result<(), OverflowError> {
+        let breadth = self.dims.1;
+        let neighbors = self.connectivity.neighbors();
+        let area = self.dims.0 * breadth;
+        let overflow = |neuron| OverflowError { neuron };
+        let scale = self.global_gain.checked_mul(duration_secs);
+
+        let mut pending = self.values.clone();
+        let mut countdowns = self.refractory.clone();
+        let held = |countdowns: &[u32], index: usize| countdowns.get(index).is_some_and(|c| *c > 0);
+
+        if let Some(bias) = &self.bias {
+            for (index, bias) in bias.iter().enumerate() {
+                if !held(&countdowns, index) {
+                    pending[index] = pending[index].checked_add(*bias).ok_or(overflow(index))?;
+                }
+            }
+        }
+
+        // Like the tick, feed back the output column with its bias added.
+        if self.recurrent_gain != S::ZERO && self.dims.0 > 0 {
+            for row in 0..breadth {
+                if !held(&countdowns, row) {
+                    pending[row] = pending[area + row]
+                        .checked_mul(self.recurrent_gain)
+                        .and_then(|fed| pending[row].checked_add(fed))
+                        .ok_or(overflow(row))?;
+                }
+            }
+        }
+
+        // A copy of the random state, so firing is decided exactly as the
+        // tick will decide it.
+        let mut stochastic = self.stochastic.clone();
+
+        // Likewise for the spikes in flight, in the Lobe's own type, so the
+        // sums that arrive are the ones checked.
+        let mut delay = self.delay.map(S::narrow);
+
+        for _ in 0..self.waves_per_tick() {
+            let fired: Vec<bool> = (0..self.values.len())
+                .map(|index| {
+                    countdowns.get(index) == Some(&0)
+                        && match &mut stochastic {
+                            None => pending[index] >= self.thresholds[self.param_index(index)],
+                            Some(stochastic) => stochastic
+                                .fires(pending[index] - self.thresholds[self.param_index(index)]),
+                        }
+                })
+                .collect();
+
+            let mut sums = Vec::with_capacity(pending.len());
+
+            for target in 0..pending.len() {
+                let (col, row) = (target / breadth, target % breadth);
+                let mut sum = S::ZERO;
+
+                for slot in 0..neighbors {
+                    let (col_offset, row_offset) = self.connectivity.offset(slot);
+
+                    let source_col = match col.checked_add_signed(-col_offset) {
+                        Some(col) if col < self.dims.0 => col,
+                        _ => continue,
+                    };
+
+                    let source_row = match row.checked_add_signed(-row_offset) {
+                        Some(row) if row < breadth => row,
+                        _ => continue,
+                    };
+
+                    let source = source_col * breadth + source_row;
+                    let param = self.param_index(source);
+
+                    if !fired[source] {
+                        continue;
+                    }
+
+                    sum = self
+                        .transfer
+                        .apply(pending[source])
+                        .checked_mul(self.weights[param * neighbors + slot])
+                        .and_then(|spike| spike.checked_mul(self.strengths[param]))
+                        .and_then(|spike| spike.checked_mul(scale?))
+                        .and_then(|spike| sum.checked_add(spike))
+                        .ok_or(overflow(target))?;
+                }
+
+                sums.push(sum);
+            }
+
+            delay.cycle(&mut sums);
+
+            let mut next = pending.clone();
+
+            for (target, (next, sum)) in next.iter_mut().zip(sums).enumerate() {
+                // Mirror the reset pass: refractory neurons stay at the reset
+                // value and receive nothing, and firing neurons restart from
+                // it, only receiving if they have no refractory period to sit
+                // out.
+                *next = match (held(&countdowns, target), fired[target]) {
+                    (true, _) => self.reset_value,
+                    (false, true) if self.refractory_ticks > 0 => self.reset_value,
+                    (false, true) => (self.reset_value.checked_add(sum)).ok_or(overflow(target))?,
+                    (false, false) => pending[target].checked_add(sum).ok_or(overflow(target))?,
+                };
+            }
+
+            for (countdown, fired) in countdowns.iter_mut().zip(&fired) {
+                if *fired {
+                    *countdown = self.refractory_ticks;
+                } else if *countdown > 0 {
+                    *countdown -= 1;
+                }
+            }
+
+            // Like the tick, the highest value of each column suppresses the
+            // rest of its column once everything has arrived.
+            if self.lateral_inhibition != S::ZERO && breadth > 0 {
+                for (col, values) in next.chunks_mut(breadth).take(self.dims.0).enumerate() {
+                    let mut winner = 0;
+
+                    for (row, value) in values.iter().enumerate() {
+                        if *value > values[winner] {
+                            winner = row;
+                        }
+                    }
+
+                    let suppression = values[winner]
+                        .checked_mul(self.lateral_inhibition)
+                        .ok_or(overflow(col * breadth + winner))?;
+
+                    if suppression <= S::ZERO {
+                        continue;
+                    }
+
+                    for (row, value) in values.iter_mut().enumerate() {
+                        let index = col * breadth + row;
+
+                        if row != winner && countdowns[index] == 0 {
+                            *value = value.checked_sub(suppression).ok_or(overflow(index))?;
+                        }
+                    }
+                }
+            }
+
+            pending = next;
+        }
+
+        for (target, value) in pending.into_iter().enumerate() {
+            let value = if value < self.min_value {
+                self.min_value
+            } else {
+                value
+            };
+
+            let falloff = match &self.falloff_per_neuron {
+                Some(falloffs) => falloffs[target],
+                None => self.falloff,
+            };
+
+            value
+                .checked_mul(falloff)
+                .and_then(|leak| leak.checked_mul(duration_secs))
+                .and_then(|leak| value.checked_sub(leak))
+                .ok_or(overflow(target))?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Amount;
+    use alloc::vec;
+
+    /// A single hidden neuron feeding the output neuron in its row.
+    fn lobe(weight: f64) -> Lobe<Amount> {
+        let mut lobe = Lobe::new(1, 1, Amount::ZERO);
+
+        lobe.weights[1] = Amount::from_num(weight);
+        lobe.strength_column_mut(0)[0] = Amount::ONE;
+        lobe
+    }
+
+    #[test]
+    fn reports_overflowing_accumulation() {
+        let mut lobe = lobe(20.0);
+
+        lobe.apply_input(&[Amount::from_num(20)]);
+
+        assert_eq!(lobe.tick_checked(1.0), Err(OverflowError { neuron: 1 }));
+        assert_eq!(lobe.value_at(0, 0), Some(Amount::from_num(20)));
+    }
+
+    #[test]
+    fn checks_feedback_of_the_biased_output() {
+        let mut lobe = lobe(0.0);
+
+        lobe.set_bias(Some(vec![Amount::from_num(5), Amount::from_num(20)]))
+            .unwrap();
+        lobe.set_recurrent_gain(Amount::ONE);
+        *lobe.value_at_mut(1, 0).unwrap() = Amount::from_num(10);
+
+        assert_eq!(lobe.tick_checked(1.0), Err(OverflowError { neuron: 0 }));
+    }
+
+    #[test]
+    fn passing_check_ticks_without_overflow() {
+        let mut lobe = lobe(0.125);
+
+        lobe.set_bias(Some(vec![Amount::from_num(5), Amount::from_num(20)]))
+            .unwrap();
+        lobe.set_recurrent_gain(Amount::ONE);
+        *lobe.value_at_mut(1, 0).unwrap() = Amount::from_num(5);
+
+        assert_eq!(lobe.tick_checked(1.0), Ok(()));
+        assert_eq!(lobe.get_output(), [Amount::from_num(28.75)]);
+    }
+
+    #[test]
+    fn checks_the_decay_too() {
+        let mut lobe = lobe(0.0);
+
+        lobe.set_falloff(Amount::from_num(3));
+        *lobe.value_at_mut(1, 0).unwrap() = Amount::from_num(16);
+
+        assert_eq!(lobe.tick_checked(1.0), Err(OverflowError { neuron: 1 }));
+        assert_eq!(lobe.get_output(), [Amount::from_num(16)]);
+    }
+
+    #[test]
+    fn refractory_neurons_receive_nothing_to_overflow() {
+        let mut lobe = Lobe::<Amount>::new(1, 2, Amount::ZERO).with_refractory_ticks(1);
+
+        lobe.weights[1] = Amount::from_num(20);
+        lobe.strengths.fill(Amount::ONE);
+        lobe.thresholds[1] = Amount::from_num(30);
+        lobe.values[1] = Amount::from_num(20);
+        lobe.apply_input(&[Amount::ONE]);
+
+        assert_eq!(lobe.tick_checked(1.0), Err(OverflowError { neuron: 1 }));
+
+        lobe.refractory[1] = 1;
+
+        assert_eq!(lobe.tick_checked(1.0), Ok(()));
+    }
+
+    #[test]
+    fn checks_every_wave_of_full_propagation() {
+        use crate::neural::lobe::PropagationMode;
+
+        let mut lobe = Lobe::<Amount>::new(1, 2, Amount::ZERO);
+
+        lobe.thresholds.fill(Amount::from_num(0.5));
+        lobe.strengths.fill(Amount::ONE);
+        lobe.weights[1] = Amount::ONE;
+        lobe.weights[4] = Amount::from_num(20);
+        lobe.apply_input(&[Amount::ONE]);
+        *lobe.value_at_mut(2, 0).unwrap() = Amount::from_num(20);
+
+        // One wave only carries the spike into the hidden neuron...
+        assert_eq!(lobe.clone().tick_checked(1.0), Ok(()));
+
+        // ...but the second wave of the same tick fires it into the output.
+        lobe.set_propagation_mode(PropagationMode::Full);
+
+        assert_eq!(lobe.tick_checked(1.0), Err(OverflowError { neuron: 2 }));
+        assert_eq!(lobe.value_at(0, 0), Some(Amount::ONE));
+    }
+
+    #[test]
+    fn checks_lateral_inhibition() {
+        let mut lobe = Lobe::<Amount>::new(2, 1, Amount::ZERO);
+
+        lobe.thresholds.fill(Amount::from_num(30));
+        lobe.apply_input(&[Amount::from_num(20), Amount::from_num(-20)]);
+
+        assert_eq!(lobe.clone().tick_checked(1.0), Ok(()));
+
+        // The first neuron pushes the second one below the range.
+        lobe.set_lateral_inhibition(Amount::ONE);
+
+        assert_eq!(lobe.tick_checked(1.0), Err(OverflowError { neuron: 1 }));
+    }
+
+    #[test]
+    fn checks_stochastic_firing_with_the_same_draws() {
+        use crate::neural::lobe::StochasticFiring;
+        use crate::rng::SplitMix64;
+
+        let mut lobe = lobe(20.0);
+
+        lobe.thresholds.fill(Amount::from_num(25));
+        lobe.apply_input(&[Amount::from_num(20)]);
+
+        assert_eq!(lobe.clone().tick_checked(1.0), Ok(()));
+
+        // Always fires within ten of the threshold.
+        let certain =
+            StochasticFiring::new(Amount::from_num(10), &[1.0], SplitMix64::seed_from_u64(67));
+
+        lobe.set_stochastic_firing(Some(certain));
+
+        assert_eq!(lobe.tick_checked(1.0), Err(OverflowError { neuron: 1 }));
+    }
+}
@@ -0,0 +1,199 @@
+/// Which neighbors each neuron of a Lobe sends its spikes to.
+///
+/// Every neuron has one forward weight per neighbor, stored contiguously in
+/// the order given by [Connectivity::offset].
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum Connectivity {
+    /// The nearest neurons in the next column, centered on the same row.
+    ///
+    /// `neighbors` must be odd, so that as many neighbors lie above the row
+    /// as below it; see [Connectivity::forward].
+    Forward { neighbors: usize },
+
+    /// All eight surrounding neurons: three in the next column, the two
+    /// vertical neighbors in the same column, and three in the previous
+    /// column. Activity diffuses in every direction instead of only flowing
+    /// towards the output.
+    Moore8,
+}
+
+impl Default for Connectivity {
+    /// The three nearest neurons in the next column.
+    fn default() -> Self {
+        Connectivity::FORWARD3
+    }
+}
+
+impl Connectivity {
+    /// The three nearest neurons in the next column.
+    pub const FORWARD3: Connectivity = Connectivity::Forward { neighbors: 3 };
+
+    /// Connects each neuron to the given number of nearest neurons in the
+    /// next column, centered on its own row.
+    ///
+    /// Panics if `neighbors` is not odd.
+    pub fn forward(neighbors: usize) -> Self {
+        assert!(
+            neighbors % 2 == 1,
+            "forward connectivity needs an odd number of neighbors, got {}",
+            neighbors
+        );
+
+        Connectivity::Forward { neighbors }
+    }
+
+    /// Returns how many neighbors, and therefore weights, each neuron has.
+    pub fn neighbors(self) -> usize {
+        match self {
+            Connectivity::Forward { neighbors } => neighbors,
+            Connectivity::Moore8 => 8,
+        }
+    }
+
+    /// Returns the `(column, row)` offset from a neuron to the neighbor its
+    /// weight at index `slot` connects to.
+    ///
+    /// The forward connections come first, from the topmost row down; with
+    /// three of them, they are shared by every connectivity.
+    pub fn offset(self, slot: usize) -> (isize, isize) {
+        const MOORE8: [(isize, isize); 8] = [
+            (1, -1),
+            (1, 0),
+            (1, 1),
+            (0, -1),
+            (0, 1),
+            (-1, -1),
+            (-1, 0),
+            (-1, 1),
+        ];
+
+        match self {
+            Connectivity::Forward { neighbors } => {
+                debug_assert!(slot < neighbors, "slot {} is out of range", slot);
+                (1, slot as isize - (neighbors / 2) as isize)
+            }
+            Connectivity::Moore8 => MOORE8[slot],
+        }
+    }
+}
+
+/// One of the three nearest forward connections of a neuron, named by where
+/// its target lies in the next column, counting rows from the top down like
+/// [Connectivity::offset].
+///
+/// These are the first three weights of every neuron under
+/// [Connectivity::FORWARD3] and [Connectivity::Moore8].
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum NeighborOffset {
+    /// The neuron one row above.
+    Left,
+
+    /// The neuron on the same row.
+    Center,
+
+    /// The neuron one row below.
+    Right,
+}
+
+impl NeighborOffset {
+    /// Returns how many rows down the target lies from the source.
+    pub fn row_offset(self) -> isize {
+        match self {
+            NeighborOffset::Left => -1,
+            NeighborOffset::Center => 0,
+            NeighborOffset::Right => 1,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::neural::base::NeuralObject;
+    use crate::neural::lobe::Lobe;
+    use crate::types::Amount;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn moore_neighborhood_surrounds_the_neuron() {
+        let mut offsets: Vec<_> = (0..8)
+            .map(|slot| Connectivity::Moore8.offset(slot))
+            .collect();
+
+        offsets.sort();
+        offsets.dedup();
+
+        assert_eq!(offsets.len(), 8);
+        assert!(offsets.iter().all(|(col, row)| (*col, *row) != (0, 0)));
+        assert!(offsets
+            .iter()
+            .all(|(col, row)| col.abs() <= 1 && row.abs() <= 1));
+    }
+
+    #[test]
+    fn moore_lobes_spread_activity_in_every_direction() {
+        let mut lobe = Lobe::<Amount>::with_connectivity(3, 2, Amount::ZERO, Connectivity::Moore8);
+
+        lobe.weights.fill(Amount::ONE);
+        lobe.strengths.fill(Amount::ONE);
+        lobe.thresholds.fill(Amount::from_num(0.5));
+        *lobe.value_at_mut(1, 1).unwrap() = Amount::ONE;
+        lobe.tick(1.0);
+
+        for col in 0..3 {
+            for row in 0..3 {
+                let expected = match (col, row) {
+                    (1, 1) => Amount::ZERO,
+                    _ => Amount::ONE,
+                };
+
+                assert_eq!(
+                    lobe.value_at(col, row).unwrap(),
+                    expected,
+                    "at ({}, {})",
+                    col,
+                    row
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn forward_neighbors_are_centered_on_the_row() {
+        let five = Connectivity::forward(5);
+        let lobe = Lobe::<Amount>::with_connectivity(2, 3, Amount::ZERO, five);
+
+        assert_eq!(five.neighbors(), 5);
+        assert_eq!(five.offset(0), (1, -2));
+        assert_eq!(five.offset(4), (1, 2));
+        assert!(lobe.weight_column_chunks(2).all(|chunk| chunk.len() == 5));
+        assert_eq!(lobe.weight_column_chunks(2).count(), 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "odd number of neighbors")]
+    fn forward_neighbors_must_be_odd() {
+        Connectivity::forward(4);
+    }
+
+    #[test]
+    fn right_weights_reach_the_row_below() {
+        let mut lobe = Lobe::new(3, 1, Amount::ZERO);
+
+        lobe.map_strengths(|_| Amount::ONE);
+        lobe.map_thresholds(|_| Amount::from_num(0.5));
+        *lobe
+            .neighbor_weight_mut(0, 1, NeighborOffset::Right)
+            .unwrap() = Amount::ONE;
+        lobe.apply_input(&[Amount::ZERO, Amount::ONE, Amount::ZERO]);
+        lobe.tick(1.0);
+
+        assert_eq!(lobe.get_output(), [Amount::ZERO, Amount::ZERO, Amount::ONE]);
+        assert_eq!(
+            lobe.neighbor_weight(0, 1, NeighborOffset::Right),
+            lobe.weight_between(0, 1, 2)
+        );
+        assert_eq!(lobe.neighbor_weight(0, 0, NeighborOffset::Left), None);
+        assert_eq!(lobe.neighbor_weight(0, 2, NeighborOffset::Right), None);
+    }
+}
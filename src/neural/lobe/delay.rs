@@ -0,0 +1,171 @@
+use crate::types::Scalar;
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// A ring buffer holding the contributions of the last few propagations, so
+/// that each arrives at its target some waves after it was computed.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(super) struct DelayLine<S> {
+    /// One frame of contributions per wave of delay, oldest at `next`.
+    frames: Vec<Vec<S>>,
+    next: usize,
+}
+
+impl<S: Scalar> DelayLine<S> {
+    /// Creates a delay line of the given number of waves, with nothing in
+    /// flight, for contributions to `size` neurons.
+    pub(super) fn new(waves: u32, size: usize) -> Self {
+        Self {
+            frames: vec![vec![S::ZERO; size]; waves as usize],
+            next: 0,
+        }
+    }
+
+    /// Returns how many waves contributions are held back for.
+    pub(super) fn waves(&self) -> u32 {
+        self.frames.len() as u32
+    }
+
+    /// Puts the fresh contributions in `outputs` in flight, and replaces them
+    /// with the ones that are due. Does nothing without a delay.
+    pub(super) fn cycle(&mut self, outputs: &mut [S]) {
+        let Some(frame) = self.frames.get_mut(self.next) else {
+            return;
+        };
+
+        // The Lobe may have been resized since the frame was last used.
+        frame.resize(outputs.len(), S::ZERO);
+        frame.swap_with_slice(outputs);
+
+        self.next = (self.next + 1) % self.frames.len();
+    }
+
+    /// Converts every contribution in flight to another type.
+    pub(super) fn map<T: Scalar>(&self, convert: impl Fn(S) -> T) -> DelayLine<T> {
+        DelayLine {
+            frames: (self.frames.iter())
+                .map(|frame| frame.iter().map(|c| convert(*c)).collect())
+                .collect(),
+            next: self.next,
+        }
+    }
+
+    /// Drops every contribution in flight.
+    pub(super) fn clear(&mut self) {
+        for frame in &mut self.frames {
+            frame.fill(S::ZERO);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::neural::base::NeuralObject;
+    use crate::neural::lobe::{Lobe, PropagationMode};
+    use crate::types::Amount;
+
+    #[test]
+    fn contributions_come_out_after_the_delay() {
+        let mut line = DelayLine::<f32>::new(2, 1);
+        let mut due = |sent| {
+            let mut outputs = [sent];
+
+            line.cycle(&mut outputs);
+            outputs[0]
+        };
+
+        assert_eq!(
+            [due(1.0), due(2.0), due(3.0), due(4.0)],
+            [0.0, 0.0, 1.0, 2.0]
+        );
+    }
+
+    #[test]
+    fn spikes_reach_the_output_after_the_delay() {
+        let mut lobe = Lobe::new(1, 1, Amount::ZERO);
+
+        lobe.map_weights(|_| Amount::ONE);
+        lobe.map_strengths(|_| Amount::ONE);
+        lobe.map_thresholds(|_| Amount::from_num(0.5));
+        lobe.set_delay_waves(2);
+        lobe.apply_input(&[Amount::ONE]);
+
+        let outputs: Vec<Amount> = (0..4)
+            .map(|_| {
+                lobe.tick(1.0);
+                lobe.get_output()[0]
+            })
+            .collect();
+
+        assert_eq!(outputs, [0, 0, 1, 1].map(Amount::from_num));
+    }
+
+    #[test]
+    fn delays_hold_spikes_back_across_columns() {
+        // Two columns, so each spike takes two waves to cross the Lobe, and
+        // a full tick carries it all the way through without a delay.
+        let mut lobe = Lobe::new(1, 2, Amount::ZERO);
+
+        lobe.map_weights(|_| Amount::ONE);
+        lobe.map_strengths(|_| Amount::ONE);
+        lobe.map_thresholds(|_| Amount::from_num(0.5));
+        lobe.set_propagation_mode(PropagationMode::Full);
+
+        let mut delayed = lobe.clone();
+
+        delayed.set_delay_waves(1);
+
+        let outputs: Vec<Vec<Amount>> = [lobe, delayed]
+            .into_iter()
+            .map(|mut lobe| {
+                lobe.apply_input(&[Amount::ONE]);
+
+                (0..2)
+                    .map(|_| {
+                        lobe.tick(1.0);
+                        lobe.get_output()[0]
+                    })
+                    .collect()
+            })
+            .collect();
+
+        assert_eq!(outputs[0], [1, 1].map(Amount::from_num));
+        assert_eq!(outputs[1], [0, 1].map(Amount::from_num));
+    }
+
+    #[test]
+    fn checked_ticks_check_the_spikes_that_arrive() {
+        let mut lobe = Lobe::new(1, 1, Amount::ZERO);
+
+        lobe.map_weights(|_| Amount::from_num(20));
+        lobe.map_strengths(|_| Amount::ONE);
+        lobe.map_thresholds(|_| Amount::from_num(0.5));
+        lobe.set_delay_waves(1);
+        lobe.apply_input(&[Amount::ONE]);
+
+        // The spike is only in flight on the first tick, and lands on top
+        // of a large output on the second.
+        assert_eq!(lobe.tick_checked(1.0), Ok(()));
+
+        lobe.value_column_mut(1)[0] = Amount::from_num(20);
+
+        assert_eq!(
+            lobe.tick_checked(1.0),
+            Err(crate::neural::lobe::OverflowError { neuron: 1 })
+        );
+    }
+
+    #[test]
+    fn clearing_drops_what_is_in_flight() {
+        let mut line = DelayLine::<f32>::new(1, 2);
+        let mut outputs = [1.0, 2.0];
+
+        line.cycle(&mut outputs);
+        line.clear();
+        line.cycle(&mut outputs);
+
+        assert_eq!(outputs, [0.0, 0.0]);
+        assert_eq!(line.waves(), 1);
+    }
+}
@@ -0,0 +1,352 @@
+use super::Lobe;
+#[cfg(feature = "std")]
+use crate::rng::Rng;
+use crate::types::Scalar;
+use alloc::vec::Vec;
+use core::fmt;
+use itertools::izip;
+
+/// An error found while combining the parameters of two Lobes.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum GeneticError {
+    /// The Lobes do not have the same dimensions.
+    DimsMismatch {
+        left: (usize, usize),
+        right: (usize, usize),
+    },
+
+    /// The Lobes do not have the same parameter layout, either because they
+    /// are connected in different patterns, or because only one of them has
+    /// per-neuron falloffs or biases.
+    LayoutMismatch,
+
+    /// The crossover mask does not have one entry per parameter.
+    MaskLength { expected: usize, got: usize },
+}
+
+impl fmt::Display for GeneticError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GeneticError::DimsMismatch { left, right } => write!(
+                f,
+                "cannot combine a Lobe of dimensions {:?} with one of dimensions {:?}",
+                left, right
+            ),
+            GeneticError::LayoutMismatch => {
+                write!(f, "cannot combine Lobes with different parameter layouts")
+            }
+            GeneticError::MaskLength { expected, got } => write!(
+                f,
+                "expected a mask of {} entries, one per parameter, got {}",
+                expected, got
+            ),
+        }
+    }
+}
+
+impl core::error::Error for GeneticError {}
+
+impl<S: Scalar> Lobe<S> {
+    /// Checks that two Lobes have the same parameter layout.
+    fn check_compatible(&self, other: &Lobe<S>) -> Result<(), GeneticError> {
+        if self.dims != other.dims {
+            return Err(GeneticError::DimsMismatch {
+                left: self.dims,
+                right: other.dims,
+            });
+        }
+
+        if self.connectivity != other.connectivity
+            || self.tied != other.tied
+            || self.falloff_per_neuron.is_some() != other.falloff_per_neuron.is_some()
+            || self.bias.is_some() != other.bias.is_some()
+        {
+            return Err(GeneticError::LayoutMismatch);
+        }
+
+        Ok(())
+    }
+
+    /// Exchanges every parameter with another Lobe of the same parameter
+    /// layout, in place and without allocating.
+    ///
+    /// The weights, strengths, thresholds, falloff and reset value are
+    /// swapped, along with the per-neuron falloffs and biases. Transient
+    /// state such as values stays with each Lobe.
+    pub fn swap_parameters(&mut self, other: &mut Lobe<S>) -> Result<(), GeneticError> {
+        self.check_compatible(other)?;
+
+        core::mem::swap(&mut self.weights, &mut other.weights);
+        core::mem::swap(&mut self.strengths, &mut other.strengths);
+        core::mem::swap(&mut self.thresholds, &mut other.thresholds);
+        core::mem::swap(&mut self.falloff, &mut other.falloff);
+        core::mem::swap(&mut self.reset_value, &mut other.reset_value);
+        core::mem::swap(&mut self.falloff_per_neuron, &mut other.falloff_per_neuron);
+        core::mem::swap(&mut self.bias, &mut other.bias);
+
+        self.sync_tied();
+        other.sync_tied();
+
+        Ok(())
+    }
+
+    /// Builds a child Lobe that takes each parameter from this Lobe where
+    /// `mask` is `true`, and from the other one where it is `false`.
+    ///
+    /// The mask has one entry per parameter, in the order of
+    /// [Lobe::all_parameters_owned]. Settings that are not parameters, such
+    /// as the learning rate, are taken from this Lobe.
+    pub fn crossover(&self, other: &Lobe<S>, mask: &[bool]) -> Result<Lobe<S>, GeneticError> {
+        self.check_compatible(other)?;
+
+        let (left, right) = (self.all_parameters_owned(), other.all_parameters_owned());
+
+        if mask.len() != left.len() {
+            return Err(GeneticError::MaskLength {
+                expected: left.len(),
+                got: mask.len(),
+            });
+        }
+
+        let params: Vec<S> = izip!(left, right, mask)
+            .map(|(left, right, take_left)| if *take_left { left } else { right })
+            .collect();
+
+        let params = self.untie_parameters(params);
+        let mut child = Lobe::try_from_parameters_with(self.dims, self.connectivity, &params)
+            .expect("parameters of compatible Lobes always load");
+
+        child.copy_settings_from(self);
+
+        Ok(child)
+    }
+
+    /// Perturbs every parameter with Gaussian noise of standard deviation
+    /// `sigma`, in place.
+    ///
+    /// Every perturbation saturates at the bounds of the scalar type instead
+    /// of overflowing. Thresholds are kept non-negative afterwards, and
+    /// falloffs, including per-neuron ones, between zero and one, so a tick
+    /// of up to a second never decays a value past zero. The reset value is
+    /// kept at or above [Lobe::min_value], which mutation never lowers.
+    /// Biases are perturbed too, if they are set. Groups frozen with
+    /// [Lobe::freeze] are left untouched.
+    ///
+    /// Needs the `std` feature, for its floating-point math.
+    #[cfg(feature = "std")]
+    pub fn mutate(&mut self, sigma: S, rng: &mut impl Rng) {
+        let sigma = sigma.to_f64();
+        let mut noise = || S::from_f64(rng.gen_normal(0.0, sigma));
+        let non_negative = |value: S| if value < S::ZERO { S::ZERO } else { value };
+        let rate = |value: S| {
+            if value > S::ONE {
+                S::ONE
+            } else {
+                non_negative(value)
+            }
+        };
+
+        let trainable = self.trainable;
+
+        if trainable.weights {
+            for weight in &mut self.weights {
+                *weight = weight.saturating_add(noise());
+            }
+        }
+
+        if trainable.strengths {
+            for strength in &mut self.strengths {
+                *strength = strength.saturating_add(noise());
+            }
+        }
+
+        if trainable.thresholds {
+            for threshold in &mut self.thresholds {
+                *threshold = non_negative(threshold.saturating_add(noise()));
+            }
+        }
+
+        if trainable.falloff {
+            self.falloff = rate(self.falloff.saturating_add(noise()));
+
+            for falloff in self.falloff_per_neuron.iter_mut().flatten() {
+                *falloff = rate(falloff.saturating_add(noise()));
+            }
+        }
+
+        if trainable.reset {
+            let reset = self.reset_value.saturating_add(noise());
+
+            self.reset_value = if reset < self.min_value {
+                self.min_value
+            } else {
+                reset
+            };
+        }
+
+        if trainable.bias {
+            for bias in self.bias.iter_mut().flatten() {
+                *bias = bias.saturating_add(noise());
+            }
+        }
+
+        self.sync_tied();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Amount;
+    use alloc::vec;
+
+    /// A Lobe whose every parameter is `value`.
+    fn filled(value: f64) -> Lobe<Amount> {
+        let mut lobe = Lobe::new(2, 2, Amount::from_num(value));
+
+        lobe.weights.fill(Amount::from_num(value));
+        lobe.strengths.fill(Amount::from_num(value));
+        lobe.thresholds.fill(Amount::from_num(value));
+        lobe.reset_value = Amount::from_num(value);
+        lobe
+    }
+
+    #[test]
+    fn swapping_twice_restores_both_lobes() {
+        let (mut left, mut right) = (filled(0.25), filled(0.5));
+        let (old_left, old_right) = (left.clone(), right.clone());
+
+        left.swap_parameters(&mut right).unwrap();
+
+        assert_eq!(
+            left.all_parameters_owned(),
+            old_right.all_parameters_owned()
+        );
+        assert_eq!(
+            right.all_parameters_owned(),
+            old_left.all_parameters_owned()
+        );
+
+        left.swap_parameters(&mut right).unwrap();
+
+        assert_eq!((left, right), (old_left, old_right));
+    }
+
+    #[test]
+    fn swapping_rejects_other_parameter_layouts() {
+        let (mut left, mut right) = (filled(0.25), filled(0.5));
+
+        right
+            .set_bias(Some(vec![Amount::ONE; right.values.len()]))
+            .unwrap();
+
+        assert_eq!(
+            left.swap_parameters(&mut right),
+            Err(GeneticError::LayoutMismatch)
+        );
+        assert_eq!(left.bias(), None);
+    }
+
+    #[test]
+    fn crossover_takes_each_parameter_as_masked() {
+        let (left, right) = (filled(0.25), filled(0.5));
+        let mask: Vec<bool> = (0..left.all_parameters_owned().len())
+            .map(|index| index % 3 == 0)
+            .collect();
+
+        let child = left.crossover(&right, &mask).unwrap();
+
+        for (param, take_left) in izip!(child.all_parameters_owned(), &mask) {
+            let expected = if *take_left { 0.25 } else { 0.5 };
+            assert_eq!(param, Amount::from_num(expected));
+        }
+    }
+
+    #[test]
+    fn crossover_keeps_the_first_parents_settings() {
+        let (mut left, right) = (filled(0.25), filled(0.5));
+
+        left.set_learning_rate(Amount::from_num(0.125));
+        left.set_refractory_ticks(3);
+        left.set_global_gain(Amount::from_num(2));
+        left.set_denoise_epsilon(Some(Amount::DELTA));
+
+        let mask = vec![false; left.all_parameters_owned().len()];
+        let child = left.crossover(&right, &mask).unwrap();
+
+        assert_eq!(child.learning_rate(), Amount::from_num(0.125));
+        assert_eq!(child.refractory_ticks, 3);
+        assert_eq!(child.global_gain(), Amount::from_num(2));
+        assert_eq!(child.denoise_epsilon(), Some(Amount::DELTA));
+        assert_eq!(child.all_parameters_owned(), right.all_parameters_owned());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn mutation_perturbs_reproducibly_keeping_thresholds_non_negative() {
+        use crate::rng::SplitMix64;
+
+        let mutated = |seed| {
+            let mut lobe = filled(0.0);
+            lobe.mutate(Amount::from_num(0.5), &mut SplitMix64::seed_from_u64(seed));
+            lobe
+        };
+
+        let lobe = mutated(9);
+
+        assert_eq!(lobe, mutated(9));
+        assert_ne!(
+            lobe.all_parameters_owned(),
+            filled(0.0).all_parameters_owned()
+        );
+        assert!(lobe.thresholds.iter().all(|t| *t >= Amount::ZERO));
+        assert!(lobe.weights.iter().any(|w| *w < Amount::ZERO));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn mutation_saturates_and_keeps_falloffs_in_range() {
+        use crate::rng::SplitMix64;
+
+        let mut lobe = filled(0.0);
+        let mut rng = SplitMix64::seed_from_u64(41);
+
+        lobe.weights.fill(Amount::MAX);
+        lobe.strengths.fill(Amount::MIN);
+        lobe.set_falloff_per_neuron(Some(vec![Amount::ONE; lobe.values.len()]))
+            .unwrap();
+        lobe.mutate(Amount::from_num(4), &mut rng);
+
+        // Wrapping around would have flipped the sign of the extremes.
+        assert!(lobe.weights.iter().all(|w| *w > Amount::ZERO));
+        assert!(lobe.strengths.iter().all(|s| *s < Amount::ZERO));
+
+        for _ in 0..8 {
+            lobe.mutate(Amount::from_num(4), &mut rng);
+
+            let falloffs = lobe.falloff_per_neuron().unwrap();
+            let in_range = |falloff: &Amount| (Amount::ZERO..=Amount::ONE).contains(falloff);
+
+            assert!(in_range(&lobe.falloff()));
+            assert!(falloffs.iter().all(in_range));
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn mutation_never_lowers_the_floor_through_the_reset() {
+        use crate::rng::SplitMix64;
+
+        let mut lobe = filled(0.0);
+        let mut rng = SplitMix64::seed_from_u64(5);
+
+        for _ in 0..16 {
+            lobe.mutate(Amount::from_num(2), &mut rng);
+
+            assert_eq!(lobe.min_value(), Amount::ZERO);
+            assert!(lobe.reset_value() >= lobe.min_value());
+        }
+
+        assert_ne!(lobe.reset_value(), Amount::ZERO);
+    }
+}
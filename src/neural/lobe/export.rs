@@ -0,0 +1,163 @@
+use super::Lobe;
+use crate::types::Scalar;
+use core::fmt::Display;
+use std::io::{self, Write};
+
+/// Writes a single CSV line, separating the cells with commas.
+fn write_line<W: Write>(w: &mut W, cells: impl Iterator<Item = impl Display>) -> io::Result<()> {
+    for (index, cell) in cells.enumerate() {
+        if index > 0 {
+            write!(w, ",")?;
+        }
+
+        write!(w, "{}", cell)?;
+    }
+
+    writeln!(w)
+}
+
+impl<S: Scalar> Lobe<S> {
+    /// Writes the current values as CSV, with one line per row of neurons
+    /// and one cell per column, including the output column.
+    pub fn write_values_csv<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        for row in 0..self.dims.1 {
+            write_line(w, self.value_row(row).map(|value| value.to_f64()))?;
+        }
+
+        Ok(())
+    }
+
+    /// Writes the Lobe's neurons and forward connections as a Graphviz DOT
+    /// graph.
+    ///
+    /// Every neuron becomes a node labeled with its threshold, laid out in
+    /// its column. Every connection whose weight is larger in magnitude than
+    /// `weight_threshold` becomes an edge, green if excitatory and red if
+    /// inhibitory, drawn thicker the heavier it is.
+    pub fn write_dot<W: Write>(&self, w: &mut W, weight_threshold: S) -> io::Result<()> {
+        let breadth = self.dims.1;
+        let neighbors = self.connectivity.neighbors();
+        let weight_threshold = weight_threshold.to_f64().abs();
+
+        writeln!(w, "digraph lobe {{")?;
+        writeln!(w, "    rankdir=LR;")?;
+
+        for col in 0..=self.dims.0 {
+            writeln!(w, "    subgraph column_{} {{", col)?;
+            writeln!(w, "        rank=same;")?;
+
+            for row in 0..breadth {
+                match self.threshold_at(col, row) {
+                    Some(threshold) => writeln!(
+                        w,
+                        "        n{}_{} [label=\"{}\"];",
+                        col,
+                        row,
+                        threshold.to_f64()
+                    )?,
+                    None => writeln!(w, "        n{}_{} [label=\"out\"];", col, row)?,
+                }
+            }
+
+            writeln!(w, "    }}")?;
+        }
+
+        for col in 0..self.dims.0 {
+            for row in 0..breadth {
+                for slot in 0..neighbors {
+                    let target = match self.neighbor_index(col, row, slot) {
+                        Some(target) => target,
+                        None => continue,
+                    };
+
+                    let source = self.param_index(col * breadth + row);
+                    let weight = self.weights[source * neighbors + slot].to_f64();
+
+                    if weight.abs() <= weight_threshold {
+                        continue;
+                    }
+
+                    writeln!(
+                        w,
+                        "    n{}_{} -> n{}_{} [color={}, penwidth={}];",
+                        col,
+                        row,
+                        target / breadth,
+                        target % breadth,
+                        if weight > 0.0 { "green" } else { "red" },
+                        1.0 + weight.abs() * 2.0
+                    )?;
+                }
+            }
+        }
+
+        writeln!(w, "}}")
+    }
+
+    /// Writes the spike counts as CSV, with one line per row of neurons and
+    /// one cell per column. The output column never fires, so it is left out.
+    pub fn write_spike_csv<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        for row in 0..self.dims.1 {
+            write_line(w, self.row_iter(&self.spike_counts, row, self.dims.0))?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::neural::base::NeuralObject;
+    use crate::types::Amount;
+    use std::string::String;
+
+    fn written(write: impl FnOnce(&mut Vec<u8>) -> io::Result<()>) -> String {
+        let mut out = vec![];
+
+        write(&mut out).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn values_are_written_row_by_row() {
+        let mut lobe = Lobe::<Amount>::new(2, 1, Amount::ZERO);
+
+        *lobe.value_at_mut(0, 1).unwrap() = Amount::from_num(0.5);
+        *lobe.value_at_mut(1, 0).unwrap() = Amount::from_num(-1.25);
+
+        assert_eq!(
+            written(|out| lobe.write_values_csv(out)),
+            "0,-1.25\n0.5,0\n"
+        );
+    }
+
+    #[test]
+    fn spike_counts_leave_out_the_output_column() {
+        let mut lobe = Lobe::<Amount>::new(2, 2, Amount::ZERO);
+
+        lobe.thresholds.fill(Amount::ONE);
+        lobe.apply_input(&[Amount::ONE, Amount::ZERO]);
+        lobe.tick(1.0);
+
+        assert_eq!(written(|out| lobe.write_spike_csv(out)), "1,0\n0,0\n");
+    }
+
+    #[test]
+    fn dot_graphs_draw_heavy_connections_only() {
+        let mut lobe = Lobe::<Amount>::new(2, 1, Amount::ZERO);
+
+        lobe.weights[2] = Amount::from_num(0.5);
+        lobe.weights[4] = Amount::from_num(-1);
+        lobe.weights[3] = Amount::from_num(0.125);
+
+        let dot = written(|out| lobe.write_dot(out, Amount::from_num(0.25)));
+
+        assert!(dot.starts_with("digraph lobe {"));
+        assert!(dot.contains("n0_1 [label=\"0\"];"));
+        assert!(dot.contains("n1_0 [label=\"out\"];"));
+        assert!(dot.contains("n0_0 -> n1_1 [color=green, penwidth=2];"));
+        assert!(dot.contains("n0_1 -> n1_1 [color=red, penwidth=3];"));
+        assert_eq!(dot.matches("->").count(), 2);
+    }
+}
@@ -0,0 +1,177 @@
+use super::{Connectivity, Lobe};
+use crate::types::Scalar;
+use core::hash::{Hash, Hasher};
+
+/// The 64-bit FNV-1a hash, which, unlike the standard library's hashers, is
+/// specified to give the same results everywhere.
+struct Fnv1a(u64);
+
+impl Fnv1a {
+    fn new() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl Hasher for Fnv1a {
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= *byte as u64;
+            self.0 = self.0.wrapping_mul(0x0000_0100_0000_01b3);
+        }
+    }
+
+    /// Writes integers in little-endian order, so that fingerprints don't
+    /// depend on the platform.
+    fn write_u64(&mut self, value: u64) {
+        self.write(&value.to_le_bytes());
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+/// Hashes a parameter by its exact bits, treating both zeros alike, since
+/// they compare equal.
+fn hash_scalar<S: Scalar, H: Hasher>(value: S, state: &mut H) {
+    let bits = if value == S::ZERO { 0 } else { value.to_bits() };
+
+    state.write_u64(bits);
+}
+
+/// Hashes a list of parameters, along with its length.
+fn hash_scalars<S: Scalar, H: Hasher>(values: &[S], state: &mut H) {
+    state.write_u64(values.len() as u64);
+
+    for value in values {
+        hash_scalar(*value, state);
+    }
+}
+
+/// Hashes the Lobe's dimensions, connectivity and parameters: the falloff,
+/// reset value, thresholds, weights, strengths, and per-neuron falloffs and
+/// biases if set. Transient state and settings are left out, so a Lobe
+/// hashes the same before and after ticking unless it learned something.
+///
+/// While tied, only the template column's thresholds, weights and strengths
+/// are hashed, like [Lobe::all_parameters_owned] lists them.
+impl<S: Scalar> Hash for Lobe<S> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // Integers are hashed as u64s, whatever their type, for fingerprints
+        // to be the same on every platform.
+        state.write_u64(self.dims.0 as u64);
+        state.write_u64(self.dims.1 as u64);
+
+        match self.connectivity {
+            Connectivity::Forward { neighbors } => {
+                state.write_u8(0);
+                state.write_u64(neighbors as u64);
+            }
+            Connectivity::Moore8 => state.write_u8(1),
+        }
+
+        let template = self.template_len();
+        let neighbors = self.connectivity.neighbors();
+
+        hash_scalar(self.falloff, state);
+        hash_scalar(self.reset_value, state);
+        hash_scalars(&self.thresholds[..template], state);
+        hash_scalars(&self.weights[..template * neighbors], state);
+        hash_scalars(&self.strengths[..template], state);
+
+        for optional in [&self.falloff_per_neuron, &self.bias] {
+            match optional {
+                Some(values) => {
+                    state.write_u8(1);
+                    hash_scalars(values, state);
+                }
+                None => state.write_u8(0),
+            }
+        }
+    }
+}
+
+impl<S: Scalar> Lobe<S> {
+    /// Returns a fingerprint of the Lobe's parameters, as hashed by its
+    /// [Hash] implementation, to cheaply detect whether training changed
+    /// anything.
+    ///
+    /// It is reproducible across runs, platforms and compiler versions, so
+    /// it can key caches that outlive the process.
+    pub fn param_fingerprint(&self) -> u64 {
+        let mut hasher = Fnv1a::new();
+
+        self.hash(&mut hasher);
+        hasher.finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Amount, I32F32};
+
+    #[test]
+    fn changes_with_any_parameter() {
+        let mut lobe = Lobe::<Amount>::new(2, 2, Amount::ZERO);
+
+        lobe.threshold_column_mut(1)[1] = Amount::from_num(31);
+
+        let before = lobe.param_fingerprint();
+
+        lobe.threshold_column_mut(1)[1] += Amount::DELTA;
+
+        assert_ne!(lobe.param_fingerprint(), before);
+
+        let before = lobe.param_fingerprint();
+
+        lobe.set_reset_value(Amount::from_num(0.5));
+
+        assert_ne!(lobe.param_fingerprint(), before);
+    }
+
+    #[test]
+    fn tells_apart_wide_fixed_point_values_one_step_apart() {
+        let mut lobe = Lobe::<I32F32>::new(2, 2, I32F32::ZERO);
+
+        lobe.threshold_column_mut(1)[1] = I32F32::from_num(1_000_000_000);
+
+        let before = lobe.param_fingerprint();
+
+        lobe.threshold_column_mut(1)[1] += I32F32::DELTA;
+
+        assert_ne!(lobe.param_fingerprint(), before);
+    }
+
+    #[test]
+    fn ignores_transient_state() {
+        let mut lobe = Lobe::<Amount>::new(2, 2, Amount::ZERO);
+        let before = lobe.param_fingerprint();
+
+        lobe[(0, 1)] = Amount::ONE;
+        lobe.set_learning_rate(Amount::ONE);
+
+        assert_eq!(lobe.param_fingerprint(), before);
+    }
+
+    #[test]
+    fn is_reproducible() {
+        let lobe = Lobe::<Amount>::new(1, 1, Amount::from_num(0.5));
+
+        assert_eq!(lobe.param_fingerprint(), 0x35a0_c8ce_a232_51d9);
+    }
+
+    #[test]
+    fn tied_hash_does_not_depend_on_the_column_edited() {
+        let mut left = Lobe::<Amount>::new(2, 3, Amount::ZERO);
+        let mut right = left.clone();
+
+        left.set_tied(true);
+        right.set_tied(true);
+        left.strength_column_mut(0)[1] = Amount::ONE;
+        right.strength_column_mut(2)[1] = Amount::ONE;
+
+        assert_eq!(left.param_fingerprint(), right.param_fingerprint());
+        assert_eq!(left.all_parameters_owned(), right.all_parameters_owned());
+    }
+}
@@ -0,0 +1,186 @@
+use super::Lobe;
+use crate::types::{Amount, Scalar};
+use alloc::boxed::Box;
+use core::panic::{RefUnwindSafe, UnwindSafe};
+use itertools::izip;
+
+/// A rule for how a Lobe learns from rewards, pluggable with
+/// [Lobe::set_learning_rule].
+///
+/// Rules must be unwind safe, like Lobes were before holding them, so that
+/// Lobes can still be used across `catch_unwind`. Rules with interior
+/// mutability can wrap it in a `Mutex`, which is. Rules should leave the
+/// parameter groups frozen in [Lobe::trainable] alone.
+pub trait LearningRule<S: Scalar = Amount>: Send + Sync + UnwindSafe + RefUnwindSafe {
+    /// Updates the Lobe's parameters in response to a reward.
+    fn apply(&self, lobe: &mut Lobe<S>, reward: S);
+
+    /// Clones this rule into a new box, so that Lobes holding it can be
+    /// cloned.
+    fn clone_box(&self) -> Box<dyn LearningRule<S>>;
+}
+
+/// The rule Lobes learn by unless given another: reward-modulated Hebbian
+/// learning through eligibility traces.
+///
+/// Every connection has its weight nudged by `reward * learning_rate` times
+/// its eligibility trace, so connections whose source fired and whose target
+/// was active on recent ticks are strengthened, or weakened by negative
+/// rewards; see [Lobe::set_trace_decay]. While the Lobe is tied, every
+/// column's traces nudge the template column's weights. Nothing changes
+/// while the weights are frozen.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+pub struct HebbianRule;
+
+impl<S: Scalar> LearningRule<S> for HebbianRule {
+    fn apply(&self, lobe: &mut Lobe<S>, reward: S) {
+        if !lobe.trainable.weights {
+            return;
+        }
+
+        let delta = reward * lobe.learning_rate;
+
+        if lobe.tied {
+            let column = lobe.dims.1 * lobe.connectivity.neighbors();
+
+            for (index, trace) in lobe.eligibility.iter().enumerate() {
+                lobe.weights[index % column] += delta * *trace;
+            }
+
+            return;
+        }
+
+        for (weight, trace) in izip!(&mut lobe.weights, &lobe.eligibility) {
+            *weight += delta * *trace;
+        }
+    }
+
+    fn clone_box(&self) -> Box<dyn LearningRule<S>> {
+        Box::new(*self)
+    }
+}
+
+/// A rule that ignores rewards, leaving the Lobe's parameters fixed.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+pub struct NullRule;
+
+impl<S: Scalar> LearningRule<S> for NullRule {
+    fn apply(&self, _lobe: &mut Lobe<S>, _reward: S) {}
+
+    fn clone_box(&self) -> Box<dyn LearningRule<S>> {
+        Box::new(*self)
+    }
+}
+
+/// The learning rule a Lobe holds, if it was given one.
+///
+/// Rules are behavior rather than state and cannot be compared, so it always
+/// compares equal, and never makes two otherwise identical Lobes differ.
+pub(super) struct Rule<S: Scalar>(pub(super) Option<Box<dyn LearningRule<S>>>);
+
+impl<S: Scalar> Clone for Rule<S> {
+    fn clone(&self) -> Self {
+        Self(self.0.as_ref().map(|rule| rule.clone_box()))
+    }
+}
+
+impl<S: Scalar> PartialEq for Rule<S> {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl<S: Scalar> Eq for Rule<S> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::neural::base::NeuralObject;
+
+    /// A rule that zeroes every weight on reward.
+    #[derive(Clone, Copy)]
+    struct ZeroRule;
+
+    impl LearningRule for ZeroRule {
+        fn apply(&self, lobe: &mut Lobe, _reward: Amount) {
+            lobe.weights.fill(Amount::ZERO);
+        }
+
+        fn clone_box(&self) -> Box<dyn LearningRule> {
+            Box::new(*self)
+        }
+    }
+
+    fn lobe() -> Lobe {
+        let mut lobe = Lobe::new(2, 2, Amount::ZERO);
+
+        lobe.weights.fill(Amount::ONE);
+        lobe
+    }
+
+    /// A Lobe whose first input fires on the next tick and whose second
+    /// doesn't.
+    fn one_firing() -> Lobe {
+        let mut lobe = Lobe::new(2, 1, Amount::ZERO);
+
+        lobe.weights.fill(Amount::from_num(0.5));
+        lobe.strengths.fill(Amount::ONE);
+        lobe.thresholds.fill(Amount::from_num(0.5));
+        lobe.apply_input(&[Amount::ONE, Amount::ZERO]);
+        lobe.tick(1.0);
+        lobe
+    }
+
+    #[test]
+    fn rewards_move_only_co_active_weights() {
+        let mut lobe = one_firing();
+
+        lobe.reward(Amount::ONE);
+
+        // The first neuron's connections to both rows, then the second
+        // neuron's to its own row.
+        assert_eq!(lobe.weights[1], Amount::from_num(0.5625));
+        assert_eq!(lobe.weights[2], Amount::from_num(0.5625));
+        assert_eq!(lobe.weights[4], Amount::from_num(0.5));
+
+        lobe.reward(-Amount::ONE);
+
+        assert_eq!(lobe.weights[1], Amount::from_num(0.5));
+    }
+
+    #[test]
+    fn reward_dispatches_to_the_rule() {
+        let mut lobe = lobe();
+
+        lobe.set_learning_rule(Some(Box::new(ZeroRule)));
+        lobe.clone().reward(Amount::ONE);
+        lobe.reward(Amount::ONE);
+
+        assert!(lobe.weight_column_ref(0).iter().all(|w| *w == Amount::ZERO));
+        assert!(lobe.learning_rule().is_some());
+    }
+
+    #[test]
+    fn null_rule_leaves_parameters_alone() {
+        let mut lobe = lobe();
+        let before = lobe.all_parameters_owned();
+
+        lobe.set_learning_rule(Some(Box::new(NullRule)));
+        lobe.reward(Amount::ONE);
+
+        assert_eq!(lobe.all_parameters_owned(), before);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn lobes_with_a_rule_cross_catch_unwind() {
+        let mut lobe = lobe();
+
+        lobe.set_learning_rule(Some(Box::new(ZeroRule)));
+
+        let caught = std::panic::catch_unwind(|| lobe.clone().reward(Amount::ONE));
+
+        assert!(caught.is_ok());
+        assert!(lobe.learning_rule().is_some());
+    }
+}
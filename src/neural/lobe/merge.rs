@@ -0,0 +1,220 @@
+use super::Lobe;
+use crate::types::Scalar;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+
+/// An error found while merging two Lobes.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum MergeError {
+    /// The Lobes do not have the same number of columns.
+    WidthMismatch { left: usize, right: usize },
+
+    /// The Lobes are not connected in the same pattern.
+    ConnectivityMismatch,
+}
+
+impl fmt::Display for MergeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MergeError::WidthMismatch { left, right } => write!(
+                f,
+                "cannot merge a Lobe of width {} with one of width {}",
+                left, right
+            ),
+            MergeError::ConnectivityMismatch => {
+                write!(f, "cannot merge Lobes with different connectivity")
+            }
+        }
+    }
+}
+
+impl core::error::Error for MergeError {}
+
+/// Interleaves two column-major buffers of `columns` columns each, taking
+/// one column from `left` and then one from `right`, so that each column of
+/// the result stacks the two.
+fn stitch<T: Copy>(
+    columns: usize,
+    left: &[T],
+    left_column: usize,
+    right: &[T],
+    right_column: usize,
+) -> Vec<T> {
+    let mut stitched = Vec::with_capacity(left.len() + right.len());
+
+    for col in 0..columns {
+        stitched.extend_from_slice(&left[col * left_column..(col + 1) * left_column]);
+        stitched.extend_from_slice(&right[col * right_column..(col + 1) * right_column]);
+    }
+
+    stitched
+}
+
+impl<S: Scalar> Lobe<S> {
+    /// Builds a new Lobe that stacks this one and another along the breadth,
+    /// with this Lobe's rows first, followed by the other's.
+    ///
+    /// Both Lobes must have the same width and connectivity. The halves start
+    /// out with no connections between them: the weights that would cross
+    /// the boundary are zeroed. Falloff is kept per neuron if the two disagree,
+    /// and biases and input normalization are kept if either Lobe has them.
+    /// The reset value, and settings such as the learning rate, refractory
+    /// period, value floor and frozen groups, are taken from this Lobe, and
+    /// transient bookkeeping such as spike counts starts anew.
+    pub fn concat_breadth(&self, other: &Lobe<S>) -> Result<Lobe<S>, MergeError> {
+        if self.dims.0 != other.dims.0 {
+            return Err(MergeError::WidthMismatch {
+                left: self.dims.0,
+                right: other.dims.0,
+            });
+        }
+
+        if self.connectivity != other.connectivity {
+            return Err(MergeError::ConnectivityMismatch);
+        }
+
+        let width = self.dims.0;
+        let neighbors = self.connectivity.neighbors();
+        let (left, right) = (self.dims.1, other.dims.1);
+        let breadth = left + right;
+
+        let mut merged = Lobe::with_connectivity(breadth, width, self.falloff, self.connectivity);
+
+        merged.values = stitch(width + 1, &self.values, left, &other.values, right);
+        merged.strengths = stitch(width, &self.strengths, left, &other.strengths, right);
+        merged.thresholds = stitch(width, &self.thresholds, left, &other.thresholds, right);
+        merged.weights = stitch(
+            width,
+            &self.weights,
+            left * neighbors,
+            &other.weights,
+            right * neighbors,
+        );
+
+        for col in 0..width {
+            for row in 0..breadth {
+                for slot in 0..neighbors {
+                    let (_, row_offset) = self.connectivity.offset(slot);
+                    let target = row as isize + row_offset;
+
+                    if (row < left) != (target < left as isize) {
+                        merged.weights[(col * breadth + row) * neighbors + slot] = S::ZERO;
+                    }
+                }
+            }
+        }
+
+        if self.falloff_per_neuron.is_some()
+            || other.falloff_per_neuron.is_some()
+            || self.falloff != other.falloff
+        {
+            let falloffs = |lobe: &Lobe<S>| {
+                lobe.falloff_per_neuron
+                    .clone()
+                    .unwrap_or_else(|| vec![lobe.falloff; lobe.values.len()])
+            };
+
+            merged.falloff_per_neuron = Some(stitch(
+                width + 1,
+                &falloffs(self),
+                left,
+                &falloffs(other),
+                right,
+            ));
+        }
+
+        if self.bias.is_some() || other.bias.is_some() {
+            let bias = |lobe: &Lobe<S>| {
+                lobe.bias
+                    .clone()
+                    .unwrap_or_else(|| vec![S::ZERO; lobe.values.len()])
+            };
+
+            merged.bias = Some(stitch(width + 1, &bias(self), left, &bias(other), right));
+        }
+
+        merged.copy_settings_from(self);
+        merged.reset_value = self.reset_value;
+
+        for (into, left, right, default) in [
+            (
+                &mut merged.input_offset,
+                &self.input_offset,
+                &other.input_offset,
+                S::ZERO,
+            ),
+            (
+                &mut merged.input_scale,
+                &self.input_scale,
+                &other.input_scale,
+                S::ONE,
+            ),
+        ] {
+            if left.is_some() || right.is_some() {
+                let mut channels = left.clone().unwrap_or_else(|| vec![default; self.dims.1]);
+                channels.extend(right.clone().unwrap_or_else(|| vec![default; other.dims.1]));
+                *into = Some(channels);
+            }
+        }
+
+        Ok(merged)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Amount;
+
+    fn filled(breadth: usize, weight: Amount, falloff: Amount) -> Lobe<Amount> {
+        let mut lobe = Lobe::new(breadth, 2, falloff);
+
+        lobe.weights.fill(weight);
+        lobe.thresholds.fill(weight);
+        lobe
+    }
+
+    #[test]
+    fn stacks_rows_without_crossing_the_boundary() {
+        let left = filled(2, Amount::ONE, Amount::ZERO);
+        let right = filled(1, Amount::from_num(0.5), Amount::ZERO);
+        let merged = left.concat_breadth(&right).unwrap();
+
+        assert_eq!(merged.get_dims(), (2, 3));
+        assert_eq!(merged.threshold_at(1, 1), Some(Amount::ONE));
+        assert_eq!(merged.threshold_at(1, 2), Some(Amount::from_num(0.5)));
+
+        // Row 0 to row 1 stays inside the left Lobe, while row 1 to row 2
+        // and row 2 to row 1 cross into the right one.
+        let weights = merged.weight_column_ref(0);
+
+        assert_eq!(weights[2], Amount::ONE);
+        assert_eq!(weights[5], Amount::ZERO);
+        assert_eq!(weights[6], Amount::ZERO);
+        assert_eq!(merged.falloff_per_neuron(), None);
+    }
+
+    #[test]
+    fn keeps_disagreeing_falloffs_per_neuron() {
+        let left = filled(1, Amount::ONE, Amount::ZERO);
+        let right = filled(1, Amount::ONE, Amount::ONE);
+        let merged = left.concat_breadth(&right).unwrap();
+
+        assert_eq!(
+            merged.falloff_per_neuron().unwrap(),
+            [Amount::ZERO, Amount::ONE].repeat(3)
+        );
+    }
+
+    #[test]
+    fn rejects_lobes_of_another_width() {
+        let left = filled(1, Amount::ONE, Amount::ZERO);
+        let right = Lobe::new(1, 3, Amount::ZERO);
+
+        assert_eq!(
+            left.concat_breadth(&right).err(),
+            Some(MergeError::WidthMismatch { left: 2, right: 3 })
+        );
+    }
+}
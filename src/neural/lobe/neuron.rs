@@ -0,0 +1,136 @@
+use super::Lobe;
+use crate::types::Scalar;
+use itertools::izip;
+
+/// Borrows everything about a single neuron of a Lobe.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct NeuronView<'a, S: Scalar> {
+    /// The neuron's column.
+    pub col: usize,
+
+    /// The neuron's row.
+    pub row: usize,
+
+    pub value: &'a S,
+    pub strength: &'a S,
+    pub threshold: &'a S,
+
+    /// The neuron's forward weights, one per [super::Connectivity::neighbors].
+    pub weights: &'a [S],
+}
+
+/// Mutably borrows everything about a single neuron of a Lobe.
+#[derive(Debug, PartialEq)]
+pub struct NeuronViewMut<'a, S: Scalar> {
+    /// The neuron's column.
+    pub col: usize,
+
+    /// The neuron's row.
+    pub row: usize,
+
+    pub value: &'a mut S,
+    pub strength: &'a mut S,
+    pub threshold: &'a mut S,
+
+    /// The neuron's forward weights, one per [super::Connectivity::neighbors].
+    pub weights: &'a mut [S],
+}
+
+impl<S: Scalar> Lobe<S> {
+    /// Iterates on every neuron outside the output column, column by column.
+    ///
+    /// Output neurons have no strength, threshold or weights, so they are
+    /// not included; see [crate::neural::base::NeuralObject::get_output].
+    /// While tied, every column shows the template's parameters.
+    pub fn neurons(&self) -> impl Iterator<Item = NeuronView<'_, S>> {
+        let breadth = self.dims.1;
+        let template = self.template_len();
+        let neighbors = self.connectivity.neighbors();
+
+        izip!(
+            &self.values[..self.thresholds.len()],
+            Self::per_neuron(&self.strengths, template),
+            Self::per_neuron(&self.thresholds, template),
+            self.weights[..template * neighbors]
+                .chunks(neighbors)
+                .cycle()
+        )
+        .enumerate()
+        .map(
+            move |(index, (value, strength, threshold, weights))| NeuronView {
+                col: index / breadth,
+                row: index % breadth,
+                value,
+                strength,
+                threshold,
+                weights,
+            },
+        )
+    }
+
+    /// Mutably iterates on every neuron outside the output column, column by
+    /// column.
+    ///
+    /// While tied, only the template column's neurons are visited, since
+    /// edits to the other columns' parameters would be overwritten.
+    pub fn neurons_mut(&mut self) -> impl Iterator<Item = NeuronViewMut<'_, S>> {
+        let breadth = self.dims.1;
+        let template = self.template_len();
+
+        izip!(
+            &mut self.values,
+            &mut self.strengths,
+            &mut self.thresholds,
+            self.weights.chunks_mut(self.connectivity.neighbors())
+        )
+        .take(template)
+        .enumerate()
+        .map(
+            move |(index, (value, strength, threshold, weights))| NeuronViewMut {
+                col: index / breadth,
+                row: index % breadth,
+                value,
+                strength,
+                threshold,
+                weights,
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Amount;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn neurons_visit_every_position_column_by_column() {
+        let mut lobe = Lobe::<Amount>::new(2, 2, Amount::ZERO);
+
+        *lobe.threshold_at_mut(1, 0).unwrap() = Amount::ONE;
+
+        let positions: Vec<_> = lobe
+            .neurons()
+            .map(|neuron| (neuron.col, neuron.row))
+            .collect();
+        let third = lobe.neurons().nth(2).unwrap();
+
+        assert_eq!(positions, [(0, 0), (0, 1), (1, 0), (1, 1)]);
+        assert_eq!(*third.threshold, Amount::ONE);
+        assert_eq!(third.weights.len(), 3);
+    }
+
+    #[test]
+    fn neurons_mut_edit_the_lobe() {
+        let mut lobe = Lobe::<Amount>::new(2, 2, Amount::ZERO);
+
+        for neuron in lobe.neurons_mut() {
+            *neuron.strength = Amount::from_num(neuron.col + neuron.row);
+            neuron.weights.fill(Amount::ONE);
+        }
+
+        assert_eq!(lobe.strength_at(1, 1), Some(Amount::from_num(2)));
+        assert!(lobe.weights.iter().all(|weight| *weight == Amount::ONE));
+    }
+}
@@ -0,0 +1,102 @@
+use crate::types::Scalar;
+
+/// How a Lobe handles values that leave its scalar type's range while spikes
+/// are accumulated and values decay.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+pub enum OverflowMode {
+    /// Uses the scalar type's own arithmetic. Fixed-point values wrap around
+    /// in release builds and panic in debug builds, and floats grow towards
+    /// infinity.
+    #[default]
+    Wrap,
+
+    /// Clamps results to the scalar type's bounds.
+    Saturate,
+
+    /// Panics as soon as a result is out of range, in every build.
+    Panic,
+}
+
+impl OverflowMode {
+    /// Adds two values, handling overflow according to this mode.
+    #[inline(always)]
+    pub(super) fn add<S: Scalar>(self, lhs: S, rhs: S) -> S {
+        match self {
+            OverflowMode::Wrap => lhs + rhs,
+            OverflowMode::Saturate => lhs.saturating_add(rhs),
+            OverflowMode::Panic => lhs.checked_add(rhs).expect("a Lobe's value overflowed"),
+        }
+    }
+
+    /// Multiplies two values, handling overflow according to this mode.
+    #[inline(always)]
+    pub(super) fn mul<S: Scalar>(self, lhs: S, rhs: S) -> S {
+        match self {
+            OverflowMode::Wrap => lhs * rhs,
+            OverflowMode::Saturate => lhs.saturating_mul(rhs),
+            OverflowMode::Panic => lhs.checked_mul(rhs).expect("a Lobe's value overflowed"),
+        }
+    }
+
+    /// Rounds a sum back from [Scalar::Wide], handling values out of the
+    /// narrow type's range according to this mode.
+    #[inline(always)]
+    pub(super) fn narrow<S: Scalar>(self, wide: S::Wide) -> S {
+        match self {
+            OverflowMode::Wrap => S::narrow(wide),
+            // Multiplying by one is exact, but checks the range like any
+            // other product.
+            _ => self.mul(S::narrow(wide), S::ONE),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::neural::base::NeuralObject;
+    use crate::neural::lobe::Lobe;
+    use crate::types::Amount;
+
+    #[test]
+    fn saturating_clamps_to_the_bounds() {
+        let mode = OverflowMode::Saturate;
+
+        assert_eq!(mode.add(Amount::MAX, Amount::ONE), Amount::MAX);
+        assert_eq!(mode.add(Amount::MIN, -Amount::ONE), Amount::MIN);
+        assert_eq!(mode.mul(Amount::MAX, Amount::from_num(2)), Amount::MAX);
+        assert_eq!(mode.add(Amount::ONE, Amount::ONE), Amount::from_num(2));
+    }
+
+    #[test]
+    fn wrapping_uses_plain_arithmetic() {
+        let mode = OverflowMode::Wrap;
+
+        assert_eq!(mode.add(f32::MAX, f32::MAX), f32::INFINITY);
+        assert_eq!(
+            mode.mul(Amount::ONE, Amount::from_num(3)),
+            Amount::from_num(3)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "a Lobe's value overflowed")]
+    fn panicking_panics_on_overflow() {
+        OverflowMode::Panic.add(Amount::MAX, Amount::ONE);
+    }
+
+    #[test]
+    fn saturating_lobes_hold_at_the_maximum() {
+        let mut lobe = Lobe::new(1, 1, Amount::ZERO);
+
+        lobe.weights.fill(Amount::MAX);
+        lobe.strengths.fill(Amount::ONE);
+        lobe.thresholds.fill(Amount::from_num(0.5));
+        lobe.set_overflow_mode(OverflowMode::Saturate);
+        lobe.apply_input(&[Amount::ONE]);
+        *lobe.value_at_mut(1, 0).unwrap() = Amount::ONE;
+        lobe.tick(1.0);
+
+        assert_eq!(lobe.get_output(), [Amount::MAX]);
+    }
+}
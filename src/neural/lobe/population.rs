@@ -0,0 +1,91 @@
+use super::Lobe;
+use crate::neural::base::NeuralObject;
+use crate::types::Scalar;
+use alloc::vec::Vec;
+
+/// Applies the same input to every Lobe of a population, ticks each `steps`
+/// times for `duration_secs`, and returns their outputs in order.
+#[cfg(not(feature = "parallel"))]
+pub fn evaluate_population<S: Scalar>(
+    lobes: &mut [Lobe<S>],
+    input: &[S],
+    steps: usize,
+    duration_secs: f64,
+) -> Vec<Vec<S>> {
+    lobes
+        .iter_mut()
+        .map(|lobe| evaluate(lobe, input, steps, duration_secs))
+        .collect()
+}
+
+/// Applies the same input to every Lobe of a population, ticks each `steps`
+/// times for `duration_secs`, and returns their outputs in order.
+///
+/// The Lobes are independent, so runs of them are handed to threads from
+/// [std::thread::scope], split by hand like a Lobe's own parallel ticks
+/// rather than through rayon. The outputs are the same as when evaluated
+/// one by one. Each Lobe's own ticks then run serially, rather than
+/// spawning more threads.
+#[cfg(feature = "parallel")]
+pub fn evaluate_population<S: Scalar>(
+    lobes: &mut [Lobe<S>],
+    input: &[S],
+    steps: usize,
+    duration_secs: f64,
+) -> Vec<Vec<S>> {
+    let jobs = std::thread::available_parallelism().map_or(1, |n| n.get());
+    let per_job = lobes.len().div_ceil(jobs).max(1);
+    let mut outputs = alloc::vec![Vec::new(); lobes.len()];
+
+    std::thread::scope(|scope| {
+        for (lobes, outputs) in lobes.chunks_mut(per_job).zip(outputs.chunks_mut(per_job)) {
+            scope.spawn(move || {
+                super::threads::become_worker();
+
+                for (lobe, output) in lobes.iter_mut().zip(outputs) {
+                    *output = evaluate(lobe, input, steps, duration_secs);
+                }
+            });
+        }
+    });
+
+    outputs
+}
+
+/// Applies the input to a single Lobe, ticks it and copies its output.
+fn evaluate<S: Scalar>(
+    lobe: &mut Lobe<S>,
+    input: &[S],
+    steps: usize,
+    duration_secs: f64,
+) -> Vec<S> {
+    lobe.apply_input(input);
+    lobe.tick_n(steps, duration_secs);
+    lobe.get_output().to_vec()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::SplitMix64;
+    use crate::types::Amount;
+    use alloc::vec;
+
+    #[test]
+    fn matches_evaluating_one_by_one() {
+        let mut rng = SplitMix64::seed_from_u64(3);
+        let mut lobes: Vec<Lobe> = (0..5)
+            .map(|_| Lobe::randomized(4, 3, Amount::from_num(0.25), &mut rng))
+            .collect();
+
+        let input = vec![Amount::ONE; 4];
+        let mut serial = lobes.clone();
+        let expected: Vec<Vec<Amount>> = serial
+            .iter_mut()
+            .map(|lobe| evaluate(lobe, &input, 4, 0.5))
+            .collect();
+
+        assert_eq!(evaluate_population(&mut lobes, &input, 4, 0.5), expected);
+        assert_eq!(lobes, serial);
+    }
+}
@@ -0,0 +1,133 @@
+#[cfg(feature = "profiling")]
+use std::time::{Duration, Instant};
+
+/// How long each phase of a Lobe's most recent tick took.
+#[cfg(feature = "profiling")]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+pub struct TickProfile {
+    /// Gathering spikes from firing neighbors.
+    pub propagation: Duration,
+
+    /// Firing neurons over their threshold and resetting them.
+    pub reset: Duration,
+
+    /// Adding the propagated spikes into the values.
+    pub accumulation: Duration,
+
+    /// Clamping and leaking the values.
+    pub decay: Duration,
+}
+
+/// The profile of a Lobe's last tick.
+///
+/// Timings vary from run to run, so it always compares equal, and never makes
+/// two otherwise identical Lobes differ.
+#[cfg(feature = "profiling")]
+#[derive(Clone, Copy, Default)]
+pub(super) struct LastProfile(pub(super) TickProfile);
+
+#[cfg(feature = "profiling")]
+impl PartialEq for LastProfile {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+#[cfg(feature = "profiling")]
+impl Eq for LastProfile {}
+
+/// A phase of a tick.
+pub(super) enum Phase {
+    Propagation,
+    Reset,
+    Accumulation,
+    Decay,
+}
+
+/// Times the phases of a tick when the `profiling` feature is enabled, and
+/// compiles down to nothing otherwise.
+pub(super) struct Stopwatch {
+    #[cfg(feature = "profiling")]
+    last: Instant,
+
+    #[cfg(feature = "profiling")]
+    profile: TickProfile,
+}
+
+impl Stopwatch {
+    /// Starts timing the first phase.
+    #[inline(always)]
+    pub(super) fn start() -> Self {
+        Stopwatch {
+            #[cfg(feature = "profiling")]
+            last: Instant::now(),
+
+            #[cfg(feature = "profiling")]
+            profile: TickProfile::default(),
+        }
+    }
+
+    /// Adds the time since the previous lap to the given phase's duration.
+    #[cfg(feature = "profiling")]
+    pub(super) fn lap(&mut self, phase: Phase) {
+        let now = Instant::now();
+        let elapsed = now - self.last;
+
+        self.last = now;
+
+        *match phase {
+            Phase::Propagation => &mut self.profile.propagation,
+            Phase::Reset => &mut self.profile.reset,
+            Phase::Accumulation => &mut self.profile.accumulation,
+            Phase::Decay => &mut self.profile.decay,
+        } += elapsed;
+    }
+
+    /// Does nothing, since profiling is disabled.
+    #[cfg(not(feature = "profiling"))]
+    #[inline(always)]
+    pub(super) fn lap(&mut self, _phase: Phase) {}
+
+    /// Returns the recorded durations.
+    #[cfg(feature = "profiling")]
+    pub(super) fn finish(self) -> TickProfile {
+        self.profile
+    }
+}
+
+#[cfg(all(test, feature = "profiling"))]
+mod tests {
+    use super::*;
+    use crate::neural::base::NeuralObject;
+    use crate::neural::lobe::Lobe;
+    use crate::types::Amount;
+
+    #[test]
+    fn laps_add_to_their_phase() {
+        let mut stopwatch = Stopwatch::start();
+
+        std::thread::sleep(Duration::from_millis(2));
+        stopwatch.lap(Phase::Decay);
+        stopwatch.lap(Phase::Reset);
+
+        let profile = stopwatch.finish();
+
+        assert!(profile.decay >= Duration::from_millis(2));
+        assert!(profile.reset < profile.decay);
+        assert_eq!(profile.propagation, Duration::ZERO);
+    }
+
+    #[test]
+    fn ticks_record_their_profile_without_affecting_equality() {
+        let mut lobe = Lobe::<Amount>::new(64, 64, Amount::ZERO);
+        let untouched = lobe.clone();
+
+        lobe.tick(1.0);
+
+        let profile = lobe.last_tick_profile();
+
+        assert!(profile.propagation > Duration::ZERO);
+        assert!(profile.decay > Duration::ZERO);
+        assert!(lobe.last_profile == untouched.last_profile);
+    }
+}
@@ -0,0 +1,68 @@
+/// How far activity travels through a Lobe on each tick.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+pub enum PropagationMode {
+    /// Activity moves one column per tick, so a spike at the input column
+    /// needs as many ticks as the Lobe is wide to reach the output. Timing
+    /// across columns is preserved.
+    #[default]
+    Stepwise,
+
+    /// Activity moves through every column within a single tick, for when
+    /// only the steady-state response matters.
+    ///
+    /// Each tick fires and propagates once per column before values decay
+    /// once, so this differs from ticking a `Stepwise` Lobe once per column,
+    /// which would also leak the values on every one of those ticks.
+    /// Refractory countdowns and spike counts advance on every propagation,
+    /// and only the last one marks eligibility traces.
+    Full,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::neural::base::NeuralObject;
+    use crate::neural::lobe::Lobe;
+    use crate::types::Amount;
+
+    /// A Lobe three columns wide that relays an input of one to its output.
+    fn relay() -> Lobe {
+        let mut lobe = Lobe::new(1, 3, Amount::from_num(0.5));
+
+        lobe.weights.fill(Amount::ONE);
+        lobe.strengths.fill(Amount::ONE);
+        lobe.thresholds.fill(Amount::from_num(0.25));
+        lobe.apply_input(&[Amount::ONE]);
+        lobe
+    }
+
+    #[test]
+    fn full_propagation_reaches_the_output_in_one_tick() {
+        let mut stepwise = relay();
+        let mut full = relay();
+        let mut once = relay();
+
+        full.set_propagation_mode(PropagationMode::Full);
+        stepwise.tick(1.0);
+        full.tick(1.0);
+        once.tick_propagate_full(1.0);
+
+        assert_eq!(stepwise.get_output(), [Amount::ZERO]);
+        assert_eq!(full.get_output(), [Amount::from_num(0.5)]);
+        assert_eq!(once.values, full.values);
+        assert_eq!(full.spike_counts(), [1, 1, 1]);
+    }
+
+    #[test]
+    fn full_propagation_decays_only_once() {
+        let mut full = relay();
+        let mut stepwise = relay();
+
+        full.set_propagation_mode(PropagationMode::Full);
+        full.tick(1.0);
+        stepwise.tick_n(3, 1.0);
+
+        assert_eq!(stepwise.get_output(), [Amount::from_num(0.125)]);
+        assert!(full.get_output()[0] > stepwise.get_output()[0]);
+    }
+}
@@ -0,0 +1,218 @@
+use super::Lobe;
+use crate::types::Scalar;
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Copies a column-major buffer of `per_neuron` entries per neuron into a
+/// new one of `columns` columns and `rows` rows, filling it with `fill`.
+///
+/// Each new column is copied from the old column `source_column` maps it to,
+/// if any, keeping the rows both have in common.
+fn remap<T: Copy>(
+    old: &[T],
+    old_rows: usize,
+    columns: usize,
+    rows: usize,
+    per_neuron: usize,
+    fill: T,
+    source_column: impl Fn(usize) -> Option<usize>,
+) -> Vec<T> {
+    let mut new = vec![fill; columns * rows * per_neuron];
+    let kept = old_rows.min(rows) * per_neuron;
+
+    for col in 0..columns {
+        if let Some(old_col) = source_column(col) {
+            let from = old_col * old_rows * per_neuron;
+            let to = col * rows * per_neuron;
+
+            new[to..to + kept].copy_from_slice(&old[from..from + kept]);
+        }
+    }
+
+    new
+}
+
+impl<S: Scalar> Lobe<S> {
+    /// Grows or shrinks this Lobe to new dimensions, given in the same order
+    /// as [Lobe::get_dims].
+    ///
+    /// Every neuron that still exists keeps its parameters and state, with
+    /// the output column staying the output column; new neurons start with
+    /// everything at zero. Neurons that no longer fit are dropped.
+    pub fn resize(&mut self, new_dims: (usize, usize)) {
+        let (old_width, old_breadth) = self.dims;
+        let (width, breadth) = new_dims;
+        let neighbors = self.connectivity.neighbors();
+
+        let hidden = |col: usize| (col < old_width).then_some(col);
+        let with_output = |col: usize| match col {
+            col if col == width => Some(old_width),
+            col => hidden(col),
+        };
+
+        self.values = remap(
+            &self.values,
+            old_breadth,
+            width + 1,
+            breadth,
+            1,
+            S::ZERO,
+            with_output,
+        );
+        self.strengths = remap(
+            &self.strengths,
+            old_breadth,
+            width,
+            breadth,
+            1,
+            S::ZERO,
+            hidden,
+        );
+        self.thresholds = remap(
+            &self.thresholds,
+            old_breadth,
+            width,
+            breadth,
+            1,
+            S::ZERO,
+            hidden,
+        );
+        self.fired = remap(&self.fired, old_breadth, width, breadth, 1, false, hidden);
+        self.refractory = remap(&self.refractory, old_breadth, width, breadth, 1, 0, hidden);
+        self.spike_counts = remap(
+            &self.spike_counts,
+            old_breadth,
+            width,
+            breadth,
+            1,
+            0,
+            hidden,
+        );
+
+        if self.rate_alpha.is_some() {
+            self.firing_rates = remap(
+                &self.firing_rates,
+                old_breadth,
+                width,
+                breadth,
+                1,
+                S::ZERO,
+                hidden,
+            );
+        }
+
+        self.weights = remap(
+            &self.weights,
+            old_breadth,
+            width,
+            breadth,
+            neighbors,
+            S::ZERO,
+            hidden,
+        );
+
+        self.eligibility = remap(
+            &self.eligibility,
+            old_breadth,
+            width,
+            breadth,
+            neighbors,
+            S::ZERO,
+            hidden,
+        );
+
+        if let Some(falloffs) = &self.falloff_per_neuron {
+            self.falloff_per_neuron = Some(remap(
+                falloffs,
+                old_breadth,
+                width + 1,
+                breadth,
+                1,
+                S::ZERO,
+                with_output,
+            ));
+        }
+
+        if let Some(bias) = &self.bias {
+            self.bias = Some(remap(
+                bias,
+                old_breadth,
+                width + 1,
+                breadth,
+                1,
+                S::ZERO,
+                with_output,
+            ));
+        }
+
+        // The input settings have a single column, so rows simply come or go.
+        for (channels, default) in [
+            (&mut self.input_offset, S::ZERO),
+            (&mut self.input_scale, S::ONE),
+        ] {
+            if let Some(channels) = channels {
+                channels.resize(breadth, default);
+            }
+        }
+
+        self.dims = new_dims;
+        self.sync_tied();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Amount;
+
+    /// A 2x2 Lobe whose thresholds tell its neurons apart.
+    fn numbered() -> Lobe<Amount> {
+        let mut lobe = Lobe::new(2, 2, Amount::ZERO);
+
+        for col in 0..2 {
+            for row in 0..2 {
+                *lobe.threshold_at_mut(col, row).unwrap() = Amount::from_num(col * 2 + row + 1);
+            }
+        }
+
+        lobe.values[4] = Amount::ONE;
+        lobe
+    }
+
+    #[test]
+    fn growing_keeps_the_old_neurons_in_place() {
+        let mut lobe = numbered();
+
+        lobe.resize((3, 3));
+
+        assert_eq!(lobe.get_dims(), (3, 3));
+        assert_eq!(lobe.values.len(), 12);
+
+        for col in 0..3 {
+            for row in 0..3 {
+                let expected = match (col, row) {
+                    (0..=1, 0..=1) => col * 2 + row + 1,
+                    _ => 0,
+                };
+
+                assert_eq!(
+                    lobe.threshold_at(col, row),
+                    Some(Amount::from_num(expected))
+                );
+            }
+        }
+
+        assert_eq!(lobe.values[9], Amount::ONE);
+    }
+
+    #[test]
+    fn shrinking_drops_the_neurons_that_no_longer_fit() {
+        let mut lobe = numbered();
+
+        lobe.resize((1, 1));
+
+        assert_eq!(lobe.thresholds, [Amount::ONE]);
+        assert_eq!(lobe.values, [Amount::ZERO, Amount::ONE]);
+        assert_eq!(lobe.weights.len(), 3);
+    }
+}
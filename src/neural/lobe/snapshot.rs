@@ -0,0 +1,99 @@
+use super::Lobe;
+use crate::error::SpikerError;
+use crate::types::{Amount, Scalar};
+
+/// The complete state of a Lobe at some point of a simulation.
+///
+/// Unlike [Lobe::all_parameters_owned], this also holds the transient state:
+/// the values, which neurons fired, refractory countdowns, spike counts and
+/// eligibility traces, as well as every setting.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LobeSnapshot<S: Scalar = Amount> {
+    lobe: Lobe<S>,
+}
+
+impl<S: Scalar> Eq for LobeSnapshot<S> where Lobe<S>: Eq {}
+
+impl<S: Scalar> LobeSnapshot<S> {
+    /// Returns the dimensions of the Lobe this was taken from.
+    pub fn get_dims(&self) -> (usize, usize) {
+        self.lobe.dims
+    }
+}
+
+impl<S: Scalar> Lobe<S> {
+    /// Captures the complete state of this Lobe, so that a simulation can be
+    /// paused and later resumed exactly with [Lobe::restore].
+    pub fn snapshot(&self) -> LobeSnapshot<S> {
+        LobeSnapshot { lobe: self.clone() }
+    }
+
+    /// Puts this Lobe back in the state a snapshot was taken in, parameters
+    /// included. Ticking afterwards reproduces the trajectory that followed
+    /// the snapshot.
+    pub fn restore(&mut self, snapshot: &LobeSnapshot<S>) {
+        self.clone_from(&snapshot.lobe);
+    }
+
+    /// Like [Lobe::restore], but fails without changing anything if the
+    /// snapshot was taken from a Lobe of different dimensions, which usually
+    /// means it belongs to another Lobe.
+    pub fn try_restore(&mut self, snapshot: &LobeSnapshot<S>) -> Result<(), SpikerError> {
+        if snapshot.lobe.dims != self.dims {
+            return Err(SpikerError::DimensionMismatch {
+                expected: self.dims,
+                got: snapshot.lobe.dims,
+            });
+        }
+
+        self.restore(snapshot);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::neural::base::NeuralObject;
+    use crate::rng::SplitMix64;
+
+    #[test]
+    fn restoring_resumes_the_same_trajectory() {
+        let mut rng = SplitMix64::seed_from_u64(11);
+        let mut lobe = Lobe::<Amount>::randomized(3, 3, Amount::from_num(0.25), &mut rng);
+
+        lobe.set_refractory_ticks(1);
+        lobe.apply_input(&[Amount::ONE; 3]);
+        lobe.tick(1.0);
+
+        let snapshot = lobe.snapshot();
+
+        lobe.tick_n(3, 1.0);
+
+        let after = lobe.clone();
+
+        lobe.restore(&snapshot);
+
+        assert_eq!(lobe.snapshot(), snapshot);
+
+        lobe.tick_n(3, 1.0);
+
+        assert_eq!(lobe, after);
+    }
+
+    #[test]
+    fn snapshots_of_other_dimensions_are_rejected() {
+        let mut lobe = Lobe::<Amount>::new(2, 2, Amount::ZERO);
+        let snapshot = Lobe::new(3, 2, Amount::ZERO).snapshot();
+
+        assert_eq!(
+            lobe.try_restore(&snapshot),
+            Err(SpikerError::DimensionMismatch {
+                expected: (2, 2),
+                got: (2, 3)
+            })
+        );
+        assert_eq!(lobe, Lobe::new(2, 2, Amount::ZERO));
+    }
+}
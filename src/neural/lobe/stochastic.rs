@@ -0,0 +1,144 @@
+use crate::rng::{Rng, SplitMix64};
+use crate::types::{Amount, Scalar};
+use alloc::vec::Vec;
+
+/// How many entries [StochasticFiring::linear] splits its ramp into.
+const LINEAR_STEPS: usize = 64;
+
+/// Makes a Lobe's neurons fire at random, with a probability that depends on
+/// how far their value is over their threshold, instead of always firing
+/// exactly at it.
+///
+/// The probability is looked up in a table of evenly spaced entries covering
+/// excesses from `-span` to `span`. Neurons further below their threshold
+/// never fire, and neurons further above it always do. The draws come from a
+/// seeded generator kept alongside the table, so runs are reproducible.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StochasticFiring<S: Scalar = Amount> {
+    span: S,
+
+    /// Firing chances, out of `1 << 32`.
+    chances: Vec<u64>,
+
+    rng: SplitMix64,
+}
+
+impl<S: Scalar> StochasticFiring<S> {
+    /// Creates a new stochastic firing rule from a table of probabilities,
+    /// from zero to one, for evenly spaced excesses from `-span` to `span`.
+    ///
+    /// Panics if the table is empty.
+    pub fn new(span: S, probabilities: &[f64], rng: SplitMix64) -> Self {
+        assert!(
+            !probabilities.is_empty(),
+            "stochastic firing needs at least one probability"
+        );
+
+        let chances = probabilities
+            .iter()
+            .map(|probability| (probability.clamp(0.0, 1.0) * (1u64 << 32) as f64) as u64)
+            .collect();
+
+        Self { span, chances, rng }
+    }
+
+    /// Creates a new stochastic firing rule whose probability ramps up
+    /// linearly, from zero at `-span` to one at `span`, so that a neuron
+    /// exactly at its threshold fires about half the time.
+    pub fn linear(span: S, rng: SplitMix64) -> Self {
+        let probabilities: Vec<f64> = (0..LINEAR_STEPS)
+            .map(|step| (step as f64 + 0.5) / LINEAR_STEPS as f64)
+            .collect();
+
+        Self::new(span, &probabilities, rng)
+    }
+
+    /// Returns how far on either side of the threshold firing is random.
+    pub fn span(&self) -> S {
+        self.span
+    }
+
+    /// Returns the probability of firing at a given excess of the value over
+    /// the threshold.
+    pub fn probability(&self, excess: S) -> f64 {
+        self.chance(excess) as f64 / (1u64 << 32) as f64
+    }
+
+    /// Looks up the firing chance, out of `1 << 32`, at an excess.
+    fn chance(&self, excess: S) -> u64 {
+        if excess < -self.span {
+            return 0;
+        }
+
+        if excess >= self.span {
+            return 1 << 32;
+        }
+
+        let position = (excess + self.span).to_f64() / (self.span + self.span).to_f64();
+        let index = (position * self.chances.len() as f64) as usize;
+
+        self.chances[index.min(self.chances.len() - 1)]
+    }
+
+    /// Draws whether a neuron fires at a given excess of its value over its
+    /// threshold.
+    pub(super) fn fires(&mut self, excess: S) -> bool {
+        let chance = self.chance(excess);
+
+        (self.rng.next_u64() >> 32) < chance
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::neural::base::NeuralObject;
+    use crate::neural::lobe::Lobe;
+
+    #[test]
+    fn probabilities_ramp_across_the_span() {
+        let firing = StochasticFiring::linear(Amount::ONE, SplitMix64::seed_from_u64(0));
+
+        assert_eq!(firing.probability(Amount::from_num(-2)), 0.0);
+        assert_eq!(firing.probability(Amount::ONE), 1.0);
+        assert!((firing.probability(Amount::ZERO) - 0.5).abs() < 0.02);
+        assert!(
+            firing.probability(Amount::from_num(-0.5)) < firing.probability(Amount::from_num(0.5))
+        );
+    }
+
+    #[test]
+    fn neurons_fire_as_often_as_the_table_says() {
+        let mut lobe = Lobe::new(1, 1, Amount::ZERO);
+
+        lobe.set_stochastic_firing(Some(StochasticFiring::new(
+            Amount::ONE,
+            &[0.0, 0.25, 0.75, 1.0],
+            SplitMix64::seed_from_u64(7),
+        )));
+        lobe.tick_n(4000, 0.1);
+
+        // A neuron at its threshold has an excess of zero, which falls in the
+        // third entry of the table.
+        let fired = lobe.spike_counts()[0];
+
+        assert!((2800..3200).contains(&fired), "fired {} times", fired);
+    }
+
+    #[test]
+    fn same_seed_fires_alike() {
+        let run = |seed| {
+            let mut lobe = Lobe::new(3, 2, Amount::ZERO);
+
+            lobe.set_stochastic_firing(Some(StochasticFiring::linear(
+                Amount::ONE,
+                SplitMix64::seed_from_u64(seed),
+            )));
+            lobe.tick_n(100, 0.1);
+            lobe.spike_counts().to_vec()
+        };
+
+        assert_eq!(run(5), run(5));
+        assert_ne!(run(5), run(6));
+    }
+}
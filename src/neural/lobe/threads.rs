@@ -0,0 +1,92 @@
+use std::cell::Cell;
+use std::sync::OnceLock;
+
+/// How many values a Lobe needs before a tick is split between threads.
+/// Spawning threads for every wave costs more than it saves below this.
+const MIN_PARALLEL_VALUES: usize = 1 << 18;
+
+std::thread_local! {
+    /// Whether this thread is already one of several running side by side.
+    static WORKER: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Returns how many threads to split work on `len` values between, which is
+/// one, for serial processing, for small Lobes and on threads that are
+/// already workers, so spawning is never nested.
+pub(super) fn jobs(len: usize) -> usize {
+    static CORES: OnceLock<usize> = OnceLock::new();
+
+    if len < MIN_PARALLEL_VALUES || WORKER.with(Cell::get) {
+        return 1;
+    }
+
+    *CORES.get_or_init(|| std::thread::available_parallelism().map_or(1, |n| n.get()))
+}
+
+/// Marks this thread as a worker, so ticks on it run serially.
+pub(super) fn become_worker() {
+    WORKER.with(|worker| worker.set(true));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::Lobe;
+    use super::*;
+    use crate::rng::SplitMix64;
+    use crate::types::Amount;
+
+    /// A Lobe with random parameters and varied values.
+    fn active_lobe(breadth: usize, width: usize) -> Lobe<Amount> {
+        let mut rng = SplitMix64::seed_from_u64(breadth as u64 * 31 + width as u64);
+        let mut lobe = Lobe::randomized(breadth, width, Amount::from_num(0.25), &mut rng);
+
+        for (index, value) in lobe.values.iter_mut().enumerate() {
+            *value = Amount::from_num((index % 7) as f64 * 0.25);
+        }
+
+        lobe
+    }
+
+    #[test]
+    fn parallel_propagation_matches_serial() {
+        let lobe = active_lobe(9, 7);
+        let dt = Amount::from_num(0.5);
+
+        let mut serial = vec![Amount::ZERO; lobe.values.len()];
+        lobe.propagate_columns(0, &mut serial, dt);
+
+        for jobs in [2, 3, 8] {
+            let mut parallel = vec![Amount::ZERO; lobe.values.len()];
+            lobe.propagate_parallel(&mut parallel, dt, jobs);
+
+            assert_eq!(parallel, serial);
+        }
+    }
+
+    #[test]
+    fn parallel_decay_matches_serial() {
+        let dt = Amount::from_num(0.5);
+        let mut serial = active_lobe(9, 7);
+        let mut parallel = serial.clone();
+
+        serial.decay(dt);
+        parallel.decay_parallel(dt, 4);
+
+        assert!(parallel == serial);
+    }
+
+    #[test]
+    fn small_lobes_stay_serial() {
+        assert_eq!(jobs(MIN_PARALLEL_VALUES - 1), 1);
+    }
+
+    #[test]
+    fn workers_stay_serial() {
+        let jobs = std::thread::spawn(|| {
+            become_worker();
+            jobs(usize::MAX)
+        });
+
+        assert_eq!(jobs.join().unwrap(), 1);
+    }
+}
@@ -0,0 +1,165 @@
+use super::Lobe;
+use crate::types::Scalar;
+
+/// A group of a Lobe's parameters that can be frozen as a whole.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum ParamGroup {
+    Weights,
+    Strengths,
+    Thresholds,
+
+    /// The shared falloff, along with the per-neuron falloffs if set.
+    Falloff,
+
+    /// The value neurons are reset to after firing.
+    Reset,
+
+    /// The per-neuron biases, if set.
+    Bias,
+}
+
+/// Which parameter groups of a Lobe are trainable. Every group is trainable
+/// by default.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct TrainableMask {
+    pub weights: bool,
+    pub strengths: bool,
+    pub thresholds: bool,
+    pub falloff: bool,
+    pub reset: bool,
+    pub bias: bool,
+}
+
+impl Default for TrainableMask {
+    fn default() -> Self {
+        TrainableMask {
+            weights: true,
+            strengths: true,
+            thresholds: true,
+            falloff: true,
+            reset: true,
+            bias: true,
+        }
+    }
+}
+
+impl TrainableMask {
+    /// Whether a parameter group is trainable.
+    pub fn is_trainable(&self, group: ParamGroup) -> bool {
+        match group {
+            ParamGroup::Weights => self.weights,
+            ParamGroup::Strengths => self.strengths,
+            ParamGroup::Thresholds => self.thresholds,
+            ParamGroup::Falloff => self.falloff,
+            ParamGroup::Reset => self.reset,
+            ParamGroup::Bias => self.bias,
+        }
+    }
+
+    /// Sets whether a parameter group is trainable.
+    pub fn set_trainable(&mut self, group: ParamGroup, trainable: bool) {
+        *match group {
+            ParamGroup::Weights => &mut self.weights,
+            ParamGroup::Strengths => &mut self.strengths,
+            ParamGroup::Thresholds => &mut self.thresholds,
+            ParamGroup::Falloff => &mut self.falloff,
+            ParamGroup::Reset => &mut self.reset,
+            ParamGroup::Bias => &mut self.bias,
+        } = trainable;
+    }
+}
+
+impl<S: Scalar> Lobe<S> {
+    /// Returns which parameter groups are trainable.
+    pub fn trainable(&self) -> TrainableMask {
+        self.trainable
+    }
+
+    /// Marks a parameter group as non-trainable, leaving it out of
+    /// [Lobe::all_parameters_slices].
+    pub fn freeze(&mut self, group: ParamGroup) {
+        self.trainable.set_trainable(group, false);
+    }
+
+    /// Marks a parameter group as trainable again.
+    pub fn thaw(&mut self, group: ParamGroup) {
+        self.trainable.set_trainable(group, true);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::neural::base::NeuralObject;
+    use crate::rng::{Rng, SplitMix64};
+    use crate::types::Amount;
+    use alloc::vec;
+
+    #[test]
+    fn frozen_groups_are_left_out_of_training() {
+        let mut lobe = Lobe::<Amount>::new(2, 1, Amount::ZERO);
+        let everything = lobe.all_parameters_slices().concat().len();
+
+        lobe.freeze(ParamGroup::Weights);
+
+        assert!(!lobe.trainable().is_trainable(ParamGroup::Weights));
+        assert_eq!(lobe.all_parameters_slices().concat().len(), everything - 6);
+
+        let grad = vec![-Amount::ONE; lobe.all_parameters_owned().len()];
+        lobe.apply_gradient(&grad, Amount::ONE).unwrap();
+
+        assert_eq!(lobe.weights, [Amount::ZERO; 6]);
+        assert_eq!(lobe.thresholds, [Amount::ONE; 2]);
+
+        lobe.thaw(ParamGroup::Weights);
+
+        assert_eq!(lobe.trainable(), TrainableMask::default());
+        assert_eq!(lobe.all_parameters_slices().concat().len(), everything);
+    }
+
+    #[test]
+    fn trainers_cannot_reach_frozen_thresholds() {
+        let mut lobe = Lobe::<Amount>::new(2, 1, Amount::ZERO);
+        let mut rng = SplitMix64::seed_from_u64(46);
+        let thresholds = lobe.thresholds.clone();
+
+        lobe.freeze(ParamGroup::Thresholds);
+
+        for slice in lobe.all_parameters_slices() {
+            for param in slice {
+                *param += Amount::from_num(rng.gen_uniform(-0.5, 0.5));
+            }
+        }
+
+        assert_eq!(lobe.thresholds, thresholds);
+        assert_ne!(lobe.weights, [Amount::ZERO; 6]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn mutation_skips_frozen_groups() {
+        let mut lobe = Lobe::<Amount>::new(2, 1, Amount::ZERO);
+        let mut rng = SplitMix64::seed_from_u64(46);
+        let (thresholds, strengths) = (lobe.thresholds.clone(), lobe.strengths.clone());
+
+        lobe.freeze(ParamGroup::Weights);
+        lobe.freeze(ParamGroup::Thresholds);
+        lobe.mutate(Amount::from_num(0.5), &mut rng);
+
+        assert_eq!(lobe.weights, [Amount::ZERO; 6]);
+        assert_eq!(lobe.thresholds, thresholds);
+        assert_ne!(lobe.strengths, strengths);
+    }
+
+    #[test]
+    fn rewards_skip_frozen_weights() {
+        let mut lobe = Lobe::<Amount>::new(2, 1, Amount::ZERO);
+
+        lobe.freeze(ParamGroup::Weights);
+        lobe.set_learning_rate(Amount::ONE);
+        lobe.eligibility.fill(Amount::ONE);
+        lobe.reward(Amount::ONE);
+
+        assert_eq!(lobe.weights, [Amount::ZERO; 6]);
+    }
+}
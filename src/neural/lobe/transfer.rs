@@ -0,0 +1,56 @@
+use crate::types::{Amount, Scalar};
+
+/// A function applied to a firing neuron's value before it is weighted and
+/// sent to its neighbors.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Transfer<S: Scalar = Amount> {
+    /// Sends the value unchanged.
+    #[default]
+    Linear,
+
+    /// Bounds the value smoothly to the given cap: `v / (1 + |v| / cap)`.
+    /// Small values pass almost unchanged, while very high values contribute
+    /// no more than the cap. A cap that is not positive lets nothing through.
+    Saturating(S),
+
+    /// Clamps negative values to zero.
+    Relu,
+}
+
+impl<S: Scalar> Transfer<S> {
+    /// Applies the transfer function to a single value.
+    pub fn apply(self, value: S) -> S {
+        match self {
+            Transfer::Linear => value,
+            Transfer::Saturating(cap) if cap <= S::ZERO => S::ZERO,
+            Transfer::Saturating(cap) => {
+                let magnitude = if value < S::ZERO { -value } else { value };
+                value / (S::ONE + magnitude / cap)
+            }
+            Transfer::Relu if value < S::ZERO => S::ZERO,
+            Transfer::Relu => value,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn saturating_stays_below_the_cap() {
+        let transfer = Transfer::Saturating(Amount::from_num(2));
+
+        assert_eq!(transfer.apply(Amount::from_num(2)), Amount::ONE);
+        assert_eq!(transfer.apply(Amount::from_num(-2)), -Amount::ONE);
+        assert!(transfer.apply(Amount::from_num(30)) < Amount::from_num(2));
+    }
+
+    #[test]
+    fn saturating_without_a_cap_lets_nothing_through() {
+        for cap in [Amount::ZERO, -Amount::ONE] {
+            assert_eq!(Transfer::Saturating(cap).apply(Amount::ONE), Amount::ZERO);
+            assert_eq!(Transfer::Saturating(cap).apply(-Amount::ONE), Amount::ZERO);
+        }
+    }
+}
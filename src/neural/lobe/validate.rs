@@ -0,0 +1,329 @@
+use super::Lobe;
+use crate::types::Scalar;
+use core::fmt;
+
+/// A problem found by [Lobe::validate] in a Lobe's state.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ValidationError {
+    /// A buffer does not have the length the dimensions call for.
+    LengthMismatch {
+        buffer: &'static str,
+        expected: usize,
+        got: usize,
+    },
+
+    /// A neuron's threshold is negative, so it would fire on every tick.
+    NegativeThreshold { index: usize },
+
+    /// An entry of a buffer is NaN or infinite.
+    NonFinite { buffer: &'static str, index: usize },
+
+    /// A scalar setting, such as the learning rate, is NaN or infinite.
+    NonFiniteSetting { setting: &'static str },
+
+    /// A falloff is negative, or so large that a tick would push values past
+    /// zero. `index` is `None` for the shared falloff, or the neuron's index
+    /// for per-neuron falloffs.
+    FalloffOutOfRange { index: Option<usize> },
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ValidationError::LengthMismatch {
+                buffer,
+                expected,
+                got,
+            } => write!(
+                f,
+                "expected {} entries in the {}, but got {}",
+                expected, buffer, got
+            ),
+            ValidationError::NegativeThreshold { index } => {
+                write!(f, "the threshold of neuron {} is negative", index)
+            }
+            ValidationError::NonFinite { buffer, index } => {
+                write!(f, "entry {} of the {} is not finite", index, buffer)
+            }
+            ValidationError::NonFiniteSetting { setting } => {
+                write!(f, "the {} is not finite", setting)
+            }
+            ValidationError::FalloffOutOfRange { index: None } => {
+                write!(f, "the falloff is out of range")
+            }
+            ValidationError::FalloffOutOfRange { index: Some(index) } => {
+                write!(f, "the falloff of neuron {} is out of range", index)
+            }
+        }
+    }
+}
+
+impl core::error::Error for ValidationError {}
+
+impl<S: Scalar> Lobe<S> {
+    /// Checks the Lobe's state for corruption, such as a long training run
+    /// diverging, and returns the first problem found.
+    ///
+    /// Every buffer must have the length the dimensions call for, every
+    /// threshold must be non-negative, every value, parameter and scalar
+    /// setting must be finite, and every falloff must be non-negative. See
+    /// [Lobe::validate_for_tick] to also bound the falloffs from above.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        let cells = self.values.len();
+        let area = self.dims.0 * self.dims.1;
+        let synapses = area * self.connectivity.neighbors();
+
+        for (buffer, expected, got) in [
+            ("values", self.dims.1 * (self.dims.0 + 1), cells),
+            ("weights", synapses, self.weights.len()),
+            ("strengths", area, self.strengths.len()),
+            ("thresholds", area, self.thresholds.len()),
+            ("eligibility traces", synapses, self.eligibility.len()),
+            ("fired flags", area, self.fired.len()),
+            ("refractory countdowns", area, self.refractory.len()),
+            ("spike counts", area, self.spike_counts.len()),
+            (
+                "firing rates",
+                self.rate_alpha.map_or(0, |_| area),
+                self.firing_rates.len(),
+            ),
+            (
+                "per-neuron falloffs",
+                cells,
+                self.falloff_per_neuron.as_ref().map_or(cells, |f| f.len()),
+            ),
+            (
+                "biases",
+                cells,
+                self.bias.as_ref().map_or(cells, |b| b.len()),
+            ),
+            (
+                "input offsets",
+                self.dims.1,
+                self.input_offset.as_ref().map_or(self.dims.1, |o| o.len()),
+            ),
+            (
+                "input scales",
+                self.dims.1,
+                self.input_scale.as_ref().map_or(self.dims.1, |s| s.len()),
+            ),
+        ] {
+            if expected != got {
+                return Err(ValidationError::LengthMismatch {
+                    buffer,
+                    expected,
+                    got,
+                });
+            }
+        }
+
+        for (buffer, entries) in [
+            ("values", &self.values[..]),
+            ("weights", &self.weights),
+            ("strengths", &self.strengths),
+            ("thresholds", &self.thresholds),
+            ("eligibility traces", &self.eligibility),
+            ("firing rates", &self.firing_rates),
+            (
+                "per-neuron falloffs",
+                self.falloff_per_neuron.as_deref().unwrap_or(&[]),
+            ),
+            ("biases", self.bias.as_deref().unwrap_or(&[])),
+            ("input offsets", self.input_offset.as_deref().unwrap_or(&[])),
+            ("input scales", self.input_scale.as_deref().unwrap_or(&[])),
+        ] {
+            if let Some(index) = entries.iter().position(|e| !e.to_f64().is_finite()) {
+                return Err(ValidationError::NonFinite { buffer, index });
+            }
+        }
+
+        for (setting, value) in [
+            ("learning rate", self.learning_rate),
+            ("trace decay", self.trace_decay),
+            ("minimum value", self.min_value),
+            ("reset value", self.reset_value),
+            ("recurrent gain", self.recurrent_gain),
+            ("global gain", self.global_gain),
+            ("lateral inhibition", self.lateral_inhibition),
+            ("rate alpha", self.rate_alpha.unwrap_or(S::ZERO)),
+            ("denoise epsilon", self.denoise_epsilon.unwrap_or(S::ZERO)),
+        ] {
+            if !value.to_f64().is_finite() {
+                return Err(ValidationError::NonFiniteSetting { setting });
+            }
+        }
+
+        if let Some(index) = self.thresholds.iter().position(|t| *t < S::ZERO) {
+            return Err(ValidationError::NegativeThreshold { index });
+        }
+
+        self.check_falloffs(f64::INFINITY)
+    }
+
+    /// Runs [Lobe::validate], and also checks that no falloff exceeds
+    /// `1 / duration_secs`, past which a tick of that duration would decay
+    /// values beyond zero.
+    pub fn validate_for_tick(&self, duration_secs: f64) -> Result<(), ValidationError> {
+        self.validate()?;
+        self.check_falloffs(1.0 / duration_secs)
+    }
+
+    /// Checks that the shared falloff and every per-neuron falloff lie
+    /// between zero and `max`.
+    fn check_falloffs(&self, max: f64) -> Result<(), ValidationError> {
+        let in_range = |falloff: S| (0.0..=max).contains(&falloff.to_f64());
+
+        if !in_range(self.falloff) {
+            return Err(ValidationError::FalloffOutOfRange { index: None });
+        }
+
+        let falloffs = self.falloff_per_neuron.as_deref().unwrap_or(&[]);
+
+        match falloffs.iter().position(|falloff| !in_range(*falloff)) {
+            Some(index) => Err(ValidationError::FalloffOutOfRange { index: Some(index) }),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Amount;
+
+    #[test]
+    fn fresh_lobes_are_valid() {
+        assert_eq!(Lobe::<Amount>::new(3, 2, Amount::ZERO).validate(), Ok(()));
+        assert_eq!(Lobe::<f32>::new(3, 2, 0.5).validate_for_tick(1.0), Ok(()));
+    }
+
+    #[test]
+    fn finds_corrupted_state() {
+        let mut lobe = Lobe::<f32>::new(2, 2, 0.0);
+
+        lobe.weights.pop();
+
+        assert_eq!(
+            lobe.validate(),
+            Err(ValidationError::LengthMismatch {
+                buffer: "weights",
+                expected: 12,
+                got: 11,
+            })
+        );
+
+        let mut lobe = Lobe::<f32>::new(2, 2, 0.0);
+
+        lobe.values[3] = f32::NAN;
+
+        assert_eq!(
+            lobe.validate(),
+            Err(ValidationError::NonFinite {
+                buffer: "values",
+                index: 3,
+            })
+        );
+
+        let mut lobe = Lobe::<f32>::new(2, 2, 0.0);
+
+        lobe.thresholds[1] = -1.0;
+
+        assert_eq!(
+            lobe.validate(),
+            Err(ValidationError::NegativeThreshold { index: 1 })
+        );
+    }
+
+    #[test]
+    fn finds_non_finite_settings() {
+        let mut lobe = Lobe::<f32>::new(2, 2, 0.0);
+
+        lobe.set_min_value(f32::NEG_INFINITY);
+
+        assert_eq!(
+            lobe.validate(),
+            Err(ValidationError::NonFiniteSetting {
+                setting: "minimum value"
+            })
+        );
+
+        let mut lobe = Lobe::<f32>::new(2, 2, 0.0);
+
+        lobe.learning_rate = f32::NAN;
+
+        assert_eq!(
+            lobe.validate(),
+            Err(ValidationError::NonFiniteSetting {
+                setting: "learning rate"
+            })
+        );
+
+        let mut lobe = Lobe::<f32>::new(2, 2, 0.0);
+
+        lobe.reset_value = f32::NAN;
+
+        assert_eq!(
+            lobe.validate(),
+            Err(ValidationError::NonFiniteSetting {
+                setting: "reset value"
+            })
+        );
+
+        let mut lobe = Lobe::<f32>::new(2, 2, 0.0);
+
+        lobe.set_denoise_epsilon(Some(f32::INFINITY));
+
+        assert_eq!(
+            lobe.validate(),
+            Err(ValidationError::NonFiniteSetting {
+                setting: "denoise epsilon"
+            })
+        );
+
+        let mut lobe = Lobe::<f32>::new(2, 2, 0.0);
+
+        lobe.set_rate_alpha(Some(f32::NAN));
+
+        assert_eq!(
+            lobe.validate(),
+            Err(ValidationError::NonFiniteSetting {
+                setting: "rate alpha"
+            })
+        );
+
+        let mut lobe = Lobe::<f32>::new(2, 2, 0.0);
+
+        lobe.set_global_gain(f32::INFINITY);
+
+        assert_eq!(
+            lobe.validate(),
+            Err(ValidationError::NonFiniteSetting {
+                setting: "global gain"
+            })
+        );
+
+        let mut lobe = Lobe::<f32>::new(2, 2, 0.0);
+
+        lobe.recurrent_gain = f32::INFINITY;
+        lobe.lateral_inhibition = f32::NAN;
+
+        assert_eq!(
+            lobe.validate(),
+            Err(ValidationError::NonFiniteSetting {
+                setting: "recurrent gain"
+            })
+        );
+    }
+
+    #[test]
+    fn falloffs_are_bounded_by_the_tick() {
+        let lobe = Lobe::<f32>::new(2, 2, 4.0);
+
+        assert_eq!(lobe.validate(), Ok(()));
+        assert_eq!(
+            lobe.validate_for_tick(0.5),
+            Err(ValidationError::FalloffOutOfRange { index: None })
+        );
+        assert_eq!(lobe.validate_for_tick(0.25), Ok(()));
+    }
+}
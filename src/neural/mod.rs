@@ -1,2 +1,10 @@
 pub mod base;
+pub mod decode;
+pub mod dense;
+pub mod driver;
+pub mod encode;
 pub mod lobe;
+pub mod network;
+pub mod parallel;
+pub mod pool;
+pub mod recorder;
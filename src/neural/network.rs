@@ -0,0 +1,190 @@
+use super::base::NeuralObject;
+use crate::types::{Amount, Scalar};
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::fmt;
+
+/// An error found while assembling a [Network].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum NetworkError {
+    /// The network has no layers.
+    Empty,
+
+    /// A layer's output does not fit the following layer's input.
+    SizeMismatch {
+        /// Index of the layer whose output does not fit.
+        layer: usize,
+        output_size: usize,
+        input_size: usize,
+    },
+}
+
+impl fmt::Display for NetworkError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NetworkError::Empty => write!(f, "a network needs at least one layer"),
+            NetworkError::SizeMismatch {
+                layer,
+                output_size,
+                input_size,
+            } => write!(
+                f,
+                "layer {} outputs {} values, but layer {} takes {} inputs",
+                layer,
+                output_size,
+                layer + 1,
+                input_size
+            ),
+        }
+    }
+}
+
+impl core::error::Error for NetworkError {}
+
+/// A sequence of neural objects, each feeding its output into the next.
+#[derive(Clone)]
+pub struct Network<S: Scalar = Amount> {
+    layers: Vec<Box<dyn NeuralObject<S>>>,
+}
+
+impl<S: Scalar> Network<S> {
+    /// Creates a new Network from a list of layers, checking that each
+    /// layer's output fits the next layer's input.
+    pub fn new(layers: Vec<Box<dyn NeuralObject<S>>>) -> Result<Self, NetworkError> {
+        if layers.is_empty() {
+            return Err(NetworkError::Empty);
+        }
+
+        for (layer, pair) in layers.windows(2).enumerate() {
+            let output_size = pair[0].output_size();
+            let input_size = pair[1].input_size();
+
+            if output_size != input_size {
+                return Err(NetworkError::SizeMismatch {
+                    layer,
+                    output_size,
+                    input_size,
+                });
+            }
+        }
+
+        Ok(Self { layers })
+    }
+
+    /// References the layers of this Network, in order.
+    pub fn layers(&self) -> &[Box<dyn NeuralObject<S>>] {
+        &self.layers
+    }
+}
+
+impl<S: Scalar> NeuralObject<S> for Network<S> {
+    fn input_size(&self) -> usize {
+        self.layers[0].input_size()
+    }
+
+    fn apply_input(&mut self, inputs: &[S]) {
+        self.layers[0].apply_input(inputs);
+    }
+
+    /// Ticks every layer in order, feeding each layer's output into the
+    /// next layer's input right after it is ticked.
+    fn tick(&mut self, duration_secs: f64) {
+        for which in 0..self.layers.len() {
+            let (head, tail) = self.layers.split_at_mut(which + 1);
+            let layer = &mut head[which];
+
+            layer.tick(duration_secs);
+
+            if let Some(next) = tail.first_mut() {
+                next.apply_input(layer.get_output());
+            }
+        }
+    }
+
+    fn get_output(&self) -> &[S] {
+        self.layers.last().unwrap().get_output()
+    }
+
+    fn output_size(&self) -> usize {
+        self.layers.last().unwrap().output_size()
+    }
+
+    /// Returns the simulated time of the first layer, since every layer is
+    /// ticked for the same duration.
+    fn elapsed_secs(&self) -> f64 {
+        self.layers[0].elapsed_secs()
+    }
+
+    fn reward(&mut self, reward: S) {
+        for layer in &mut self.layers {
+            layer.reward(reward);
+        }
+    }
+
+    fn reset(&mut self) {
+        for layer in &mut self.layers {
+            layer.reset();
+        }
+    }
+
+    fn clone_box(&self) -> Box<dyn NeuralObject<S>> {
+        Box::new(self.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::neural::lobe::Lobe;
+    use alloc::vec;
+
+    /// A Lobe that passes an input of one straight through to its output.
+    fn relay(breadth: usize) -> Box<dyn NeuralObject> {
+        let area = breadth;
+        let mut params = vec![Amount::from_num(0.5); area];
+
+        params.extend(vec![Amount::ONE; area * 4]);
+        params.extend([Amount::ZERO, Amount::ZERO]);
+        Box::new(Lobe::from_parameters((1, breadth), &params))
+    }
+
+    #[test]
+    fn feeds_each_layer_into_the_next() {
+        let mut network = Network::new(vec![relay(1), relay(1)]).unwrap();
+
+        network.apply_input(&[Amount::ONE]);
+        network.tick(1.0);
+
+        assert_eq!(network.layers()[0].get_output(), [Amount::ONE]);
+        assert_eq!(network.get_output(), [Amount::ONE]);
+    }
+
+    #[test]
+    fn rejects_layers_that_do_not_fit() {
+        assert_eq!(
+            Network::<Amount>::new(vec![]).err(),
+            Some(NetworkError::Empty)
+        );
+        assert_eq!(
+            Network::new(vec![relay(1), relay(2)]).err(),
+            Some(NetworkError::SizeMismatch {
+                layer: 0,
+                output_size: 1,
+                input_size: 2,
+            })
+        );
+    }
+
+    #[test]
+    fn clones_are_independent() {
+        let network = Network::new(vec![relay(1), relay(1)]).unwrap();
+        let mut ticked = network.clone();
+
+        ticked.apply_input(&[Amount::ONE]);
+        ticked.tick(1.0);
+
+        assert_eq!(ticked.get_output(), [Amount::ONE]);
+        assert_eq!(network.get_output(), [Amount::ZERO]);
+        assert_eq!(network.elapsed_secs(), 0.0);
+    }
+}
@@ -0,0 +1,147 @@
+use super::base::NeuralObject;
+use super::network::NetworkError;
+use crate::types::{Amount, Scalar};
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+
+/// Several neural objects side by side, each taking its own slice of the
+/// combined input, with their outputs concatenated in order.
+///
+/// Together with [Network](super::network::Network), this builds
+/// tree-structured networks. The concatenated output is refreshed on every
+/// tick and reset.
+#[derive(Clone)]
+pub struct Parallel<S: Scalar = Amount> {
+    branches: Vec<Box<dyn NeuralObject<S>>>,
+    outputs: Vec<S>,
+}
+
+impl<S: Scalar> Parallel<S> {
+    /// Creates a new Parallel from a list of branches, the first taking the
+    /// first inputs and producing the first outputs.
+    pub fn new(branches: Vec<Box<dyn NeuralObject<S>>>) -> Result<Self, NetworkError> {
+        if branches.is_empty() {
+            return Err(NetworkError::Empty);
+        }
+
+        let mut parallel = Self {
+            branches,
+            outputs: Vec::new(),
+        };
+
+        parallel.refresh();
+
+        Ok(parallel)
+    }
+
+    /// References the branches of this Parallel, in order.
+    pub fn branches(&self) -> &[Box<dyn NeuralObject<S>>] {
+        &self.branches
+    }
+
+    /// Concatenates the branches' current outputs.
+    fn refresh(&mut self) {
+        self.outputs.clear();
+
+        for branch in &self.branches {
+            self.outputs.extend_from_slice(branch.get_output());
+        }
+    }
+}
+
+impl<S: Scalar> NeuralObject<S> for Parallel<S> {
+    fn input_size(&self) -> usize {
+        self.branches.iter().map(|branch| branch.input_size()).sum()
+    }
+
+    /// Splits the inputs between the branches, in order, each taking as many
+    /// as its input size. If the inputs run out, the remaining branches get
+    /// fewer or none, and any excess is ignored.
+    fn apply_input(&mut self, mut inputs: &[S]) {
+        for branch in &mut self.branches {
+            let (head, tail) = inputs.split_at(branch.input_size().min(inputs.len()));
+
+            branch.apply_input(head);
+            inputs = tail;
+        }
+    }
+
+    fn tick(&mut self, duration_secs: f64) {
+        for branch in &mut self.branches {
+            branch.tick(duration_secs);
+        }
+
+        self.refresh();
+    }
+
+    fn get_output(&self) -> &[S] {
+        &self.outputs
+    }
+
+    fn output_size(&self) -> usize {
+        self.branches
+            .iter()
+            .map(|branch| branch.output_size())
+            .sum()
+    }
+
+    /// Returns the simulated time of the first branch, since every branch is
+    /// ticked for the same duration.
+    fn elapsed_secs(&self) -> f64 {
+        self.branches[0].elapsed_secs()
+    }
+
+    fn reward(&mut self, reward: S) {
+        for branch in &mut self.branches {
+            branch.reward(reward);
+        }
+    }
+
+    fn reset(&mut self) {
+        for branch in &mut self.branches {
+            branch.reset();
+        }
+
+        self.refresh();
+    }
+
+    fn clone_box(&self) -> Box<dyn NeuralObject<S>> {
+        Box::new(self.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::neural::lobe::Lobe;
+    use alloc::vec;
+
+    /// A Lobe whose input column doubles as its output column.
+    fn passthrough(breadth: usize) -> Box<dyn NeuralObject> {
+        Box::new(Lobe::new(breadth, 0, Amount::ZERO))
+    }
+
+    #[test]
+    fn splits_inputs_and_concatenates_outputs() {
+        let mut parallel = Parallel::new(vec![passthrough(2), passthrough(2)]).unwrap();
+        let inputs = [1.0, 0.5, 0.25, 0.0].map(Amount::from_num);
+
+        assert_eq!(parallel.input_size(), 4);
+        assert_eq!(parallel.output_size(), 4);
+
+        parallel.apply_input(&inputs);
+        parallel.tick(1.0);
+
+        assert_eq!(parallel.branches()[0].get_output(), &inputs[..2]);
+        assert_eq!(parallel.branches()[1].get_output(), &inputs[2..]);
+        assert_eq!(parallel.get_output(), inputs);
+    }
+
+    #[test]
+    fn needs_a_branch() {
+        assert_eq!(
+            Parallel::<Amount>::new(vec![]).err(),
+            Some(NetworkError::Empty)
+        );
+    }
+}
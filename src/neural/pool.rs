@@ -0,0 +1,174 @@
+use super::base::NeuralObject;
+use crate::types::{Amount, Scalar};
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+
+/// What a [MaxPool] takes the maximum of.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+pub enum PoolBy {
+    /// The highest value in each window.
+    #[default]
+    Value,
+
+    /// Whether any output in each window spikes, that is, is positive: one
+    /// if so, zero otherwise.
+    Spike,
+}
+
+/// Wraps a neural object, downsampling its output by taking the maximum over
+/// consecutive windows of a fixed size.
+///
+/// This lets a wide layer feed a narrower one in a
+/// [Network](super::network::Network). If the window size does not divide
+/// the wrapped output, the last window is shorter. The pooled output is
+/// refreshed on every tick and reset.
+#[derive(Clone)]
+pub struct MaxPool<N: NeuralObject<S>, S: Scalar = Amount> {
+    inner: N,
+    window: usize,
+    pool_by: PoolBy,
+    pooled: Vec<S>,
+}
+
+impl<N: NeuralObject<S>, S: Scalar> MaxPool<N, S> {
+    /// Creates a new MaxPool around a neural object, with the given window
+    /// size.
+    ///
+    /// Panics if `window` is zero.
+    pub fn new(inner: N, window: usize, pool_by: PoolBy) -> Self {
+        assert!(window > 0, "a pooling window must not be empty");
+
+        let mut pool = Self {
+            inner,
+            window,
+            pool_by,
+            pooled: Vec::new(),
+        };
+
+        pool.refresh();
+        pool
+    }
+
+    /// Returns how many of the wrapped object's outputs are pooled together.
+    pub fn window(&self) -> usize {
+        self.window
+    }
+
+    /// Returns what each window takes the maximum of.
+    pub fn pool_by(&self) -> PoolBy {
+        self.pool_by
+    }
+
+    /// References the wrapped neural object.
+    pub fn inner(&self) -> &N {
+        &self.inner
+    }
+
+    /// Mutably references the wrapped neural object.
+    pub fn inner_mut(&mut self) -> &mut N {
+        &mut self.inner
+    }
+
+    /// Unwraps the neural object.
+    pub fn into_inner(self) -> N {
+        self.inner
+    }
+
+    /// Pools the wrapped object's current output.
+    fn refresh(&mut self) {
+        let pool_by = self.pool_by;
+
+        self.pooled.clear();
+        self.pooled
+            .extend(self.inner.get_output().chunks(self.window).map(|window| {
+                let max = window.iter().fold(
+                    window[0],
+                    |max, value| {
+                        if *value > max {
+                            *value
+                        } else {
+                            max
+                        }
+                    },
+                );
+
+                match pool_by {
+                    PoolBy::Value => max,
+                    PoolBy::Spike if max > S::ZERO => S::ONE,
+                    PoolBy::Spike => S::ZERO,
+                }
+            }));
+    }
+}
+
+impl<N: NeuralObject<S> + Clone + 'static, S: Scalar> NeuralObject<S> for MaxPool<N, S> {
+    fn input_size(&self) -> usize {
+        self.inner.input_size()
+    }
+
+    fn apply_input(&mut self, inputs: &[S]) {
+        self.inner.apply_input(inputs);
+    }
+
+    fn tick(&mut self, duration_secs: f64) {
+        self.inner.tick(duration_secs);
+        self.refresh();
+    }
+
+    fn get_output(&self) -> &[S] {
+        &self.pooled
+    }
+
+    fn output_size(&self) -> usize {
+        self.inner.output_size().div_ceil(self.window)
+    }
+
+    fn reward(&mut self, reward: S) {
+        self.inner.reward(reward);
+    }
+
+    fn elapsed_secs(&self) -> f64 {
+        self.inner.elapsed_secs()
+    }
+
+    fn reset(&mut self) {
+        self.inner.reset();
+        self.refresh();
+    }
+
+    fn clone_box(&self) -> Box<dyn NeuralObject<S>> {
+        Box::new(self.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::neural::lobe::Lobe;
+
+    /// Pools a known output, set up on a Lobe whose input column doubles as
+    /// its output column.
+    fn pooled(pool_by: PoolBy) -> MaxPool<Lobe> {
+        let mut pool = MaxPool::new(Lobe::new(5, 0, Amount::ZERO), 2, pool_by);
+
+        pool.apply_input(&[0.25, 0.5, 0.0, 0.0, 0.75].map(Amount::from_num));
+        pool.tick(1.0);
+        pool
+    }
+
+    #[test]
+    fn takes_the_maximum_of_each_window() {
+        let pool = pooled(PoolBy::Value);
+
+        assert_eq!(pool.output_size(), 3);
+        assert_eq!(pool.get_output(), [0.5, 0.0, 0.75].map(Amount::from_num));
+    }
+
+    #[test]
+    fn pooling_by_spike_flags_positive_windows() {
+        assert_eq!(
+            pooled(PoolBy::Spike).get_output(),
+            [Amount::ONE, Amount::ZERO, Amount::ONE]
+        );
+    }
+}
@@ -0,0 +1,147 @@
+use super::base::NeuralObject;
+use crate::types::{Amount, Scalar};
+use alloc::boxed::Box;
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Wraps a neural object, recording which of its outputs fired on every tick.
+///
+/// An output counts as firing when its value is above the recorder's
+/// threshold, which defaults to zero.
+#[derive(Clone)]
+pub struct Recorder<N: NeuralObject<S>, S: Scalar = Amount> {
+    inner: N,
+    threshold: S,
+    history: Vec<Vec<bool>>,
+}
+
+impl<N: NeuralObject<S>, S: Scalar> Recorder<N, S> {
+    /// Creates a new Recorder around a neural object, with an empty history.
+    pub fn new(inner: N) -> Self {
+        Self {
+            inner,
+            threshold: S::ZERO,
+            history: vec![],
+        }
+    }
+
+    /// Returns this Recorder with the given firing threshold.
+    pub fn with_threshold(mut self, threshold: S) -> Self {
+        self.threshold = threshold;
+        self
+    }
+
+    /// References the recorded frames, one per tick, in order.
+    pub fn history(&self) -> &[Vec<bool>] {
+        &self.history
+    }
+
+    /// Forgets all recorded frames.
+    pub fn clear(&mut self) {
+        self.history.clear();
+    }
+
+    /// References the wrapped neural object.
+    pub fn inner(&self) -> &N {
+        &self.inner
+    }
+
+    /// Mutably references the wrapped neural object.
+    pub fn inner_mut(&mut self) -> &mut N {
+        &mut self.inner
+    }
+
+    /// Unwraps the neural object, discarding the history.
+    pub fn into_inner(self) -> N {
+        self.inner
+    }
+}
+
+impl<N: NeuralObject<S> + Clone + 'static, S: Scalar> NeuralObject<S> for Recorder<N, S> {
+    fn input_size(&self) -> usize {
+        self.inner.input_size()
+    }
+
+    fn apply_input(&mut self, inputs: &[S]) {
+        self.inner.apply_input(inputs);
+    }
+
+    fn tick(&mut self, duration_secs: f64) {
+        self.inner.tick(duration_secs);
+
+        let frame = self
+            .inner
+            .get_output()
+            .iter()
+            .map(|value| *value > self.threshold)
+            .collect();
+
+        self.history.push(frame);
+    }
+
+    fn get_output(&self) -> &[S] {
+        self.inner.get_output()
+    }
+
+    fn output_size(&self) -> usize {
+        self.inner.output_size()
+    }
+
+    fn reward(&mut self, reward: S) {
+        self.inner.reward(reward);
+    }
+
+    fn elapsed_secs(&self) -> f64 {
+        self.inner.elapsed_secs()
+    }
+
+    fn reset(&mut self) {
+        self.inner.reset();
+    }
+
+    fn clone_box(&self) -> Box<dyn NeuralObject<S>> {
+        Box::new(self.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::neural::lobe::Lobe;
+
+    /// A Lobe whose output is one half after a tick with an input of one,
+    /// and halves on every tick after.
+    fn fading() -> Lobe {
+        let half = Amount::from_num(0.5);
+        let one = Amount::ONE;
+
+        Lobe::from_parameters((1, 1), &[half, one, one, one, one, half, Amount::ZERO])
+    }
+
+    #[test]
+    fn records_a_frame_per_tick() {
+        let mut recorder = Recorder::new(fading()).with_threshold(Amount::from_num(0.3));
+
+        recorder.apply_input(&[Amount::ONE]);
+        recorder.tick(1.0);
+        recorder.tick(1.0);
+
+        assert_eq!(recorder.history(), [vec![true], vec![false]]);
+        assert_eq!(recorder.get_output(), [Amount::from_num(0.25)]);
+
+        recorder.clear();
+
+        assert!(recorder.history().is_empty());
+    }
+
+    #[test]
+    fn default_threshold_counts_any_positive_output() {
+        let mut recorder = Recorder::new(fading());
+
+        recorder.tick(1.0);
+        recorder.apply_input(&[Amount::ONE]);
+        recorder.tick(1.0);
+
+        assert_eq!(recorder.history(), [vec![false], vec![true]]);
+    }
+}
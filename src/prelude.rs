@@ -0,0 +1,45 @@
+//! Re-exports the types needed for common usage, so that a single
+//! `use spiker::prelude::*;` brings them all in.
+//!
+//! ```
+//! use spiker::prelude::*;
+//!
+//! let mut lobe: Lobe = LobeBuilder::new().dims((3, 2)).build().unwrap();
+//!
+//! lobe.apply_input(&[Amount::ONE, Amount::ZERO]);
+//! lobe.tick(0.1);
+//!
+//! assert_eq!(lobe.get_output().len(), 2);
+//! ```
+
+pub use crate::error::SpikerError;
+pub use crate::neural::base::NeuralObject;
+pub use crate::neural::dense::{Activation, DenseLayer};
+pub use crate::neural::lobe::{Connectivity, Lobe, LobeBuilder};
+pub use crate::neural::network::Network;
+pub use crate::rng::{Rng, SplitMix64};
+pub use crate::types::{Amount, FixedPrecision, Scalar};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::boxed::Box;
+    use alloc::vec;
+
+    #[test]
+    fn brings_in_everything_to_build_a_network() {
+        let mut rng = SplitMix64::seed_from_u64(1);
+        let first: Lobe = Lobe::randomized(2, 2, Amount::ZERO, &mut rng);
+        let second: Lobe = LobeBuilder::new()
+            .dims((1, 2))
+            .connectivity(Connectivity::Forward { neighbors: 3 })
+            .build()
+            .unwrap();
+        let mut network = Network::new(vec![Box::new(first), Box::new(second)]).unwrap();
+
+        network.apply_input(&[Amount::ONE, Amount::ONE]);
+        network.tick(0.1);
+
+        assert_eq!(network.output_size(), 2);
+    }
+}
@@ -0,0 +1,108 @@
+/// A source of random numbers, used for parameter initialization.
+pub trait Rng {
+    /// Returns the next 64 random bits.
+    fn next_u64(&mut self) -> u64;
+
+    /// Samples a float uniformly from `[0, 1)`.
+    fn gen_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// Samples a float uniformly from `[low, high)`.
+    fn gen_uniform(&mut self, low: f64, high: f64) -> f64 {
+        low + (high - low) * self.gen_f64()
+    }
+
+    /// Samples a float from a normal distribution, using the Box-Muller
+    /// transform. Needs the `std` feature, for its floating-point math.
+    #[cfg(feature = "std")]
+    fn gen_normal(&mut self, mean: f64, std_dev: f64) -> f64 {
+        let u1 = 1.0 - self.gen_f64();
+        let u2 = self.gen_f64();
+
+        mean + std_dev * (-2.0 * u1.ln()).sqrt() * (core::f64::consts::TAU * u2).cos()
+    }
+}
+
+/// A small, fast, seedable generator (SplitMix64).
+///
+/// The same seed always produces the same sequence, which makes it suitable
+/// for reproducible experiments.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SplitMix64 {
+    state: u64,
+}
+
+impl SplitMix64 {
+    /// Creates a new generator from a seed.
+    pub fn seed_from_u64(seed: u64) -> Self {
+        Self { state: seed }
+    }
+}
+
+impl Rng for SplitMix64 {
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+}
+
+/// A distribution to sample parameters from.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Distribution {
+    /// Uniform over `[low, high)`.
+    Uniform { low: f64, high: f64 },
+
+    /// Normal with the given mean and standard deviation. Needs the `std`
+    /// feature.
+    #[cfg(feature = "std")]
+    Normal { mean: f64, std_dev: f64 },
+}
+
+impl Distribution {
+    /// Draws a single sample from this distribution.
+    pub fn sample(&self, rng: &mut impl Rng) -> f64 {
+        match *self {
+            Distribution::Uniform { low, high } => rng.gen_uniform(low, high),
+            #[cfg(feature = "std")]
+            Distribution::Normal { mean, std_dev } => rng.gen_normal(mean, std_dev),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_seed_same_sequence() {
+        let mut left = SplitMix64::seed_from_u64(7);
+        let mut right = SplitMix64::seed_from_u64(7);
+        let mut other = SplitMix64::seed_from_u64(8);
+
+        for _ in 0..16 {
+            let next = left.next_u64();
+
+            assert_eq!(next, right.next_u64());
+            assert_ne!(next, other.next_u64());
+        }
+    }
+
+    #[test]
+    fn uniform_samples_stay_in_range() {
+        let mut rng = SplitMix64::seed_from_u64(1);
+        let distribution = Distribution::Uniform {
+            low: -0.5,
+            high: 0.25,
+        };
+
+        for _ in 0..1000 {
+            let sample = distribution.sample(&mut rng);
+            assert!((-0.5..0.25).contains(&sample));
+        }
+    }
+}
@@ -0,0 +1,77 @@
+use crate::types::Amount;
+
+/// How many values are processed together.
+const LANES: usize = 16;
+
+/// The raw bits behind an [Amount].
+type Bits = i16;
+
+/// An integer twice as wide as [Bits], to hold products.
+type Wide = i32;
+
+/// Multiplies two raw fixed-point values exactly the way the fixed-point type
+/// does, so lanes stay bit-identical to scalar arithmetic.
+#[inline(always)]
+fn mul_bits(lhs: Bits, rhs: Bits) -> Bits {
+    let int_nbits = Bits::BITS - Amount::FRAC_NBITS;
+    let product = (lhs as Wide).wrapping_mul((rhs as Wide) << int_nbits);
+
+    (product >> Bits::BITS) as Bits
+}
+
+/// Leaks every value towards zero at the falloff rate, working on fixed-size
+/// lanes of raw bits that the compiler can map onto vector instructions.
+///
+/// Overflow wraps, as it does for the fixed-point type in release builds.
+pub(crate) fn decay_amounts(values: &mut [Amount], falloff: Amount, duration_secs: Amount) {
+    let falloff = falloff.to_bits();
+    let duration_secs = duration_secs.to_bits();
+
+    let mut chunks = values.chunks_exact_mut(LANES);
+
+    for chunk in &mut chunks {
+        let mut lanes: [Bits; LANES] = [0; LANES];
+
+        for (lane, value) in lanes.iter_mut().zip(chunk.iter()) {
+            *lane = value.to_bits();
+        }
+
+        for lane in &mut lanes {
+            *lane = lane.wrapping_sub(mul_bits(mul_bits(*lane, falloff), duration_secs));
+        }
+
+        for (value, lane) in chunk.iter_mut().zip(lanes) {
+            *value = Amount::from_bits(lane);
+        }
+    }
+
+    for value in chunks.into_remainder() {
+        let bits = value.to_bits();
+        *value =
+            Amount::from_bits(bits.wrapping_sub(mul_bits(mul_bits(bits, falloff), duration_secs)));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_scalar_decay_bit_for_bit() {
+        // Enough values to fill two lanes and leave a remainder.
+        let mut values: Vec<Amount> = (0..37)
+            .map(|index| Amount::from_bits((index * 1733 - 31000) as i16))
+            .collect();
+
+        let falloff = Amount::from_num(0.3);
+        let duration_secs = Amount::from_num(0.7);
+        let expected: Vec<Amount> = values
+            .iter()
+            .map(|value| *value - *value * falloff * duration_secs)
+            .collect();
+
+        decay_amounts(&mut values, falloff, duration_secs);
+
+        assert_eq!(values, expected);
+    }
+}
@@ -1,3 +1,498 @@
+use core::ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Sub, SubAssign};
 use fixed;
 
 pub type Amount = fixed::types::I6F10;
+
+pub use fixed::types::{I16F16, I32F32};
+
+#[cfg(feature = "half")]
+pub use half::f16;
+
+/// A numeric type neural objects can compute with.
+///
+/// This is implemented for the fixed-point [Amount], which keeps simulations
+/// deterministic, for the wider fixed-point [I16F16] and [I32F32], when
+/// `Amount`'s range or resolution is too small, and for `f32` and `f64`,
+/// which are handy for prototyping.
+/// With the `half` feature, it is also implemented for [f16], which
+/// halves the memory of large Lobes at the cost of precision.
+pub trait Scalar:
+    Copy
+    + PartialOrd
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+    + AddAssign
+    + SubAssign
+    + MulAssign
+    + Send
+    + Sync
+    + 'static
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    /// Identifies the type in binary Lobes, which can only be read back as
+    /// the type they were written with; see
+    /// [Lobe::to_bytes](crate::neural::lobe::Lobe::to_bytes).
+    const TAG: u8;
+
+    /// Converts from a float, saturating if it is out of range.
+    fn from_f64(num: f64) -> Self;
+
+    /// Converts to a float.
+    fn to_f64(self) -> f64;
+
+    /// The type Lobes sum the spikes a neuron receives in, before rounding
+    /// the sum back once. It is the type itself, except for narrow floats.
+    type Wide: Scalar;
+
+    /// Converts to [Scalar::Wide], exactly.
+    fn widen(self) -> Self::Wide;
+
+    /// Rounds back from [Scalar::Wide]. Floats out of range become infinite.
+    fn narrow(wide: Self::Wide) -> Self;
+
+    /// Returns the value's exact representation, in the low bits. Types
+    /// implementing this trait must be at most eight bytes large.
+    fn to_bits(self) -> u64;
+
+    /// Converts back from [Scalar::to_bits], ignoring any bits beyond the
+    /// type's size.
+    fn from_bits(bits: u64) -> Self;
+
+    /// Adds, returning `None` on overflow.
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+
+    /// Subtracts, returning `None` on overflow.
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+
+    /// Multiplies, returning `None` on overflow.
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+
+    /// Adds, clamping to the representable range instead of overflowing.
+    fn saturating_add(self, rhs: Self) -> Self;
+
+    /// Multiplies, clamping to the representable range instead of overflowing.
+    fn saturating_mul(self, rhs: Self) -> Self;
+
+    /// Leaks every value towards zero at the falloff rate.
+    fn decay(values: &mut [Self], falloff: Self, duration_secs: Self) {
+        for value in values {
+            *value -= *value * falloff * duration_secs;
+        }
+    }
+}
+
+impl Scalar for Amount {
+    const ZERO: Self = Amount::ZERO;
+    const ONE: Self = Amount::ONE;
+    const TAG: u8 = 1;
+
+    fn from_f64(num: f64) -> Self {
+        Amount::saturating_from_num(num)
+    }
+
+    fn to_f64(self) -> f64 {
+        self.to_num()
+    }
+
+    type Wide = Self;
+
+    fn widen(self) -> Self {
+        self
+    }
+
+    fn narrow(wide: Self) -> Self {
+        wide
+    }
+
+    fn to_bits(self) -> u64 {
+        Amount::to_bits(self) as u16 as u64
+    }
+
+    fn from_bits(bits: u64) -> Self {
+        Amount::from_bits(bits as i16)
+    }
+
+    fn checked_add(self, rhs: Self) -> Option<Self> {
+        Amount::checked_add(self, rhs)
+    }
+
+    fn checked_sub(self, rhs: Self) -> Option<Self> {
+        Amount::checked_sub(self, rhs)
+    }
+
+    fn checked_mul(self, rhs: Self) -> Option<Self> {
+        Amount::checked_mul(self, rhs)
+    }
+
+    fn saturating_add(self, rhs: Self) -> Self {
+        Amount::saturating_add(self, rhs)
+    }
+
+    fn saturating_mul(self, rhs: Self) -> Self {
+        Amount::saturating_mul(self, rhs)
+    }
+
+    #[cfg(feature = "simd")]
+    fn decay(values: &mut [Self], falloff: Self, duration_secs: Self) {
+        crate::simd::decay_amounts(values, falloff, duration_secs);
+    }
+}
+
+/// The integer and fractional bit split of a fixed-point [Scalar], to reason
+/// about its range and resolution.
+pub trait FixedPrecision {
+    /// Returns how many bits the integer and fractional parts have, sign
+    /// included in the integer part. With `(int_bits, frac_bits)`, values
+    /// range from `-2^(int_bits - 1)` to just under `2^(int_bits - 1)`, in
+    /// steps of `2^-frac_bits`.
+    fn precision_info() -> (u32, u32);
+}
+
+impl<F: fixed::traits::Fixed> FixedPrecision for F {
+    fn precision_info() -> (u32, u32) {
+        (F::INT_NBITS, F::FRAC_NBITS)
+    }
+}
+
+/// Implements [Scalar] for fixed-point types other than [Amount], which
+/// alone has a SIMD decay, given their tag and the unsigned integer of their
+/// size.
+macro_rules! fixed_scalar {
+    ($($fixed:ty: $tag:literal, $bits:ty),*) => {$(
+        impl Scalar for $fixed {
+            const ZERO: Self = <$fixed>::ZERO;
+            const ONE: Self = <$fixed>::ONE;
+            const TAG: u8 = $tag;
+
+            fn from_f64(num: f64) -> Self {
+                <$fixed>::saturating_from_num(num)
+            }
+
+            fn to_f64(self) -> f64 {
+                self.to_num()
+            }
+
+            type Wide = Self;
+
+            fn widen(self) -> Self {
+                self
+            }
+
+            fn narrow(wide: Self) -> Self {
+                wide
+            }
+
+            fn to_bits(self) -> u64 {
+                <$fixed>::to_bits(self) as $bits as u64
+            }
+
+            fn from_bits(bits: u64) -> Self {
+                <$fixed>::from_bits(bits as $bits as _)
+            }
+
+            fn checked_add(self, rhs: Self) -> Option<Self> {
+                <$fixed>::checked_add(self, rhs)
+            }
+
+            fn checked_sub(self, rhs: Self) -> Option<Self> {
+                <$fixed>::checked_sub(self, rhs)
+            }
+
+            fn checked_mul(self, rhs: Self) -> Option<Self> {
+                <$fixed>::checked_mul(self, rhs)
+            }
+
+            fn saturating_add(self, rhs: Self) -> Self {
+                <$fixed>::saturating_add(self, rhs)
+            }
+
+            fn saturating_mul(self, rhs: Self) -> Self {
+                <$fixed>::saturating_mul(self, rhs)
+            }
+        }
+    )*};
+}
+
+fixed_scalar!(I16F16: 2, u32, I32F32: 3, u64);
+
+impl Scalar for f32 {
+    const ZERO: Self = 0.0;
+    const ONE: Self = 1.0;
+    const TAG: u8 = 5;
+
+    fn from_f64(num: f64) -> Self {
+        num as f32
+    }
+
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+
+    type Wide = Self;
+
+    fn widen(self) -> Self {
+        self
+    }
+
+    fn narrow(wide: Self) -> Self {
+        wide
+    }
+
+    fn to_bits(self) -> u64 {
+        f32::to_bits(self) as u64
+    }
+
+    fn from_bits(bits: u64) -> Self {
+        f32::from_bits(bits as u32)
+    }
+
+    fn checked_add(self, rhs: Self) -> Option<Self> {
+        Some(self + rhs).filter(|sum| sum.is_finite())
+    }
+
+    fn checked_sub(self, rhs: Self) -> Option<Self> {
+        Some(self - rhs).filter(|difference| difference.is_finite())
+    }
+
+    fn checked_mul(self, rhs: Self) -> Option<Self> {
+        Some(self * rhs).filter(|product| product.is_finite())
+    }
+
+    fn saturating_add(self, rhs: Self) -> Self {
+        (self + rhs).clamp(Self::MIN, Self::MAX)
+    }
+
+    fn saturating_mul(self, rhs: Self) -> Self {
+        (self * rhs).clamp(Self::MIN, Self::MAX)
+    }
+}
+
+impl Scalar for f64 {
+    const ZERO: Self = 0.0;
+    const ONE: Self = 1.0;
+    const TAG: u8 = 6;
+
+    fn from_f64(num: f64) -> Self {
+        num
+    }
+
+    fn to_f64(self) -> f64 {
+        self
+    }
+
+    type Wide = Self;
+
+    fn widen(self) -> Self {
+        self
+    }
+
+    fn narrow(wide: Self) -> Self {
+        wide
+    }
+
+    fn to_bits(self) -> u64 {
+        f64::to_bits(self)
+    }
+
+    fn from_bits(bits: u64) -> Self {
+        f64::from_bits(bits)
+    }
+
+    fn checked_add(self, rhs: Self) -> Option<Self> {
+        Some(self + rhs).filter(|sum| sum.is_finite())
+    }
+
+    fn checked_sub(self, rhs: Self) -> Option<Self> {
+        Some(self - rhs).filter(|difference| difference.is_finite())
+    }
+
+    fn checked_mul(self, rhs: Self) -> Option<Self> {
+        Some(self * rhs).filter(|product| product.is_finite())
+    }
+
+    fn saturating_add(self, rhs: Self) -> Self {
+        (self + rhs).clamp(Self::MIN, Self::MAX)
+    }
+
+    fn saturating_mul(self, rhs: Self) -> Self {
+        (self * rhs).clamp(Self::MIN, Self::MAX)
+    }
+}
+
+/// Half-precision storage. Each arithmetic operation is carried out in `f32`
+/// and rounded back to `f16`. Lobes also propagate in `f32`, through
+/// [Scalar::Wide], rounding each neuron's sum of incoming spikes only once.
+#[cfg(feature = "half")]
+impl Scalar for f16 {
+    const ZERO: Self = f16::ZERO;
+    const ONE: Self = f16::ONE;
+    const TAG: u8 = 4;
+
+    fn from_f64(num: f64) -> Self {
+        f16::from_f64(num.clamp(f16::MIN.to_f64(), f16::MAX.to_f64()))
+    }
+
+    fn to_f64(self) -> f64 {
+        f16::to_f64(self)
+    }
+
+    type Wide = f32;
+
+    fn widen(self) -> f32 {
+        self.to_f32()
+    }
+
+    fn narrow(wide: f32) -> Self {
+        f16::from_f32(wide)
+    }
+
+    fn to_bits(self) -> u64 {
+        f16::to_bits(self) as u64
+    }
+
+    fn from_bits(bits: u64) -> Self {
+        f16::from_bits(bits as u16)
+    }
+
+    fn checked_add(self, rhs: Self) -> Option<Self> {
+        Some(self + rhs).filter(|sum| sum.is_finite())
+    }
+
+    fn checked_sub(self, rhs: Self) -> Option<Self> {
+        Some(self - rhs).filter(|difference| difference.is_finite())
+    }
+
+    fn checked_mul(self, rhs: Self) -> Option<Self> {
+        Some(self * rhs).filter(|product| product.is_finite())
+    }
+
+    fn saturating_add(self, rhs: Self) -> Self {
+        (self + rhs).clamp(f16::MIN, f16::MAX)
+    }
+
+    fn saturating_mul(self, rhs: Self) -> Self {
+        (self * rhs).clamp(f16::MIN, f16::MAX)
+    }
+
+    /// Leaks every value in `f32`, rounding only the result back to `f16`.
+    fn decay(values: &mut [Self], falloff: Self, duration_secs: Self) {
+        let rate = falloff.to_f32() * duration_secs.to_f32();
+
+        for value in values {
+            let wide = value.to_f32();
+            *value = f16::from_f32(wide - wide * rate);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::neural::base::NeuralObject;
+    use crate::neural::lobe::Lobe;
+    use alloc::vec::Vec;
+
+    /// Three hidden neurons all sending `spike` to the middle output neuron,
+    /// which starts at `start`.
+    fn converging<S: Scalar>(start: f64, spike: f64) -> Lobe<S> {
+        let mut lobe = Lobe::new(3, 1, S::ZERO);
+
+        lobe.weight_column_mut(0).fill(S::from_f64(spike));
+        lobe.strength_column_mut(0).fill(S::ONE);
+        lobe.apply_input(&[S::ONE; 3]);
+        lobe.value_column_mut(1)[1] = S::from_f64(start);
+        lobe
+    }
+
+    fn ticked_output<S: Scalar>() -> Vec<f64> {
+        let mut lobe = converging::<S>(0.25, 0.5);
+
+        lobe.tick(1.0);
+        lobe.get_output()
+            .iter()
+            .map(|value| value.to_f64())
+            .collect()
+    }
+
+    #[test]
+    fn every_scalar_ticks_alike() {
+        let expected = ticked_output::<Amount>();
+
+        assert_eq!(expected, [1.0, 1.75, 1.0]);
+        assert_eq!(ticked_output::<f32>(), expected);
+        assert_eq!(ticked_output::<f64>(), expected);
+    }
+
+    #[test]
+    fn scalars_round_trip_through_their_bits() {
+        fn round_trip<S: Scalar>(value: f64) {
+            let value = S::from_f64(value);
+            assert_eq!(S::from_bits(value.to_bits()).to_f64(), value.to_f64());
+        }
+
+        for value in [0.0, -1.5, 3.25] {
+            round_trip::<Amount>(value);
+            round_trip::<I16F16>(value);
+            round_trip::<I32F32>(value);
+            round_trip::<f32>(value);
+            round_trip::<f64>(value);
+        }
+    }
+
+    #[test]
+    fn fixed_point_saturates_from_out_of_range_floats() {
+        assert_eq!(Amount::from_f64(1e9), Amount::MAX);
+        assert_eq!(Amount::from_f64(-1e9), Amount::MIN);
+    }
+
+    #[cfg(feature = "half")]
+    #[test]
+    fn half_lobes_tick_like_single_precision_ones() {
+        let mut half = converging::<f16>(0.3, 0.1);
+        let mut single = converging::<f32>(0.3, 0.1);
+
+        half.tick(1.0);
+        single.tick(1.0);
+
+        for (half, single) in half.get_output().iter().zip(single.get_output()) {
+            assert!((half.to_f32() - single).abs() < 1e-3);
+        }
+    }
+
+    #[cfg(feature = "half")]
+    #[test]
+    fn half_lobes_sum_spikes_before_rounding() {
+        // Around 2048, f16 values are two apart, so each spike alone would
+        // round away, but their sum of 2.25 does not.
+        let mut lobe = converging::<f16>(2048.0, 0.75);
+
+        lobe.tick(1.0);
+
+        assert_eq!(lobe.get_output()[1], f16::from_f32(2050.0));
+    }
+
+    #[test]
+    fn fixed_point_types_report_their_precision() {
+        assert_eq!(Amount::precision_info(), (6, 10));
+        assert_eq!(I16F16::precision_info(), (16, 16));
+        assert_eq!(I32F32::precision_info(), (32, 32));
+    }
+
+    #[test]
+    fn wider_fixed_point_ticks_past_the_range_of_amount() {
+        let (int_bits, _) = I16F16::precision_info();
+        let mut lobe = converging::<I16F16>(1000.0, 100.0);
+
+        assert!(1300.0 < (1u64 << (int_bits - 1)) as f64);
+        assert_eq!(Amount::from_f64(1300.0), Amount::MAX);
+
+        lobe.tick(1.0);
+
+        assert_eq!(lobe.get_output()[1], I16F16::from_num(1300));
+    }
+}